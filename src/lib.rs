@@ -1,35 +1,148 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::{BitAnd, BitOr, Sub};
+
 /// Represents a discrete set of objects.
 #[derive(Debug, Clone)]
 pub struct Set<T> {
 
-    /// The objects.
+    /// The objects, sorted and without repeats.
     items: Vec<T>,
 }
 
+/// Represents the ways in which building a `Set<T>` can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetError<T> {
+    /// The given object appears more than once.
+    Duplicate(T),
+}
+
+/// Compares two `Set<T>` by their objects.
+///
+/// The objects are kept sorted, so this is a single linear comparison.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::Set;
+/// let a = Set::new(&(0..5000).collect::<Vec<i32>>());
+/// let b = Set::new(&(0..5000).rev().collect::<Vec<i32>>());
+/// let c = Set::new(&(1..5001).collect::<Vec<i32>>());
+/// assert_eq!(a, b);
+/// assert_ne!(a, c);
+/// ```
 impl<T: Ord + Clone> PartialEq for Set<T> {
     fn eq(&self, other: &Set<T>) -> bool {
-        let ref a = *self.items;
-        if self.items.len() != other.items.len() {
-            return false;
-        }
-        for item in a {
-            if !other.has(item) {
-                return false;
-            }
-        }
-        return true;
+        self.items == other.items
+    }
+}
+
+impl<T: Ord + Clone> Eq for Set<T> {}
+
+impl<T: Ord + Clone> PartialOrd for Set<T> {
+    fn partial_cmp(&self, other: &Set<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord + Clone> Ord for Set<T> {
+    fn cmp(&self, other: &Set<T>) -> Ordering {
+        self.items.cmp(&other.items)
+    }
+}
+
+/// Hashes a `Set<T>` by its sorted objects, so that equal sets hash equally
+/// regardless of the order they were created from.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::Set;
+/// # use std::collections::HashMap;
+/// let mut m = HashMap::new();
+/// m.insert(Set::new(&vec![1, 2]), "first");
+/// m.insert(Set::new(&vec![2, 1]), "second");
+/// assert_eq!(m.len(), 1);
+/// assert_eq!(m.get(&Set::new(&vec![1, 2, 2])), Some(&"second"));
+/// ```
+impl<T: Ord + Clone + Hash> Hash for Set<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.items.hash(state);
+    }
+}
+
+impl<T> IntoIterator for Set<T> {
+    type Item = T;
+    type IntoIter = ::std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> ::std::vec::IntoIter<T> {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Set<T> {
+    type Item = &'a T;
+    type IntoIter = ::std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> ::std::slice::Iter<'a, T> {
+        self.items.iter()
     }
 }
 
 impl<T: Ord + Clone> Set<T> {
-    /// Creates a new `Set<T>` with a given `Vec` of objects.
+    /// Creates a new `Set<T>` from the objects of a `Vec`.
+    ///
+    /// The objects are kept sorted, and repeated objects are only kept once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&vec![1, 1, 2]);
+    /// assert_eq!(s.len(), 2);
+    /// assert_eq!(s, Set::new(&vec![1, 2]));
+    /// assert_eq!(s.as_vec(), vec![1, 2]);
+    /// ```
+    #[allow(clippy::ptr_arg)]
     pub fn new(items: &Vec<T>) -> Set<T> {
-        Set { items: items.clone() }
+        let mut v = items.clone();
+        v.sort();
+        v.dedup();
+        Set { items: v }
+    }
+
+    /// Creates a new, empty `Set<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s: Set<i32> = Set::empty();
+    /// assert_eq!(s.len(), 0);
+    /// ```
+    pub fn empty() -> Set<T> {
+        Set { items: Vec::new() }
+    }
+
+    /// Creates a new `Set<T>` containing only the given object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// assert_eq!(Set::singleton(3).len(), 1);
+    /// assert_eq!(Set::singleton(3), Set::singleton(3));
+    /// assert_eq!(Set::singleton(3), Set::new(&vec![3]));
+    /// ```
+    pub fn singleton(x: T) -> Set<T> {
+        Set { items: vec![x] }
     }
 
     /// Returns `true` if this `Set<T>` contains the given object.
     pub fn has(&self, l: &T) -> bool {
-        self.items.iter().any(|x| x == l)
+        self.items.binary_search(l).is_ok()
     }
 
     /// Returns the size of this `Set<T>`.
@@ -37,324 +150,3567 @@ impl<T: Ord + Clone> Set<T> {
         self.items.len()
     }
 
+    /// Returns `true` if this `Set<T>` contains no objects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// assert!(Set::<i32>::empty().is_empty());
+    /// assert!(!Set::new(&vec![0]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
     /// Creates a `vec` containing the objects.
     pub fn as_vec(&self) -> Vec<T> {
         self.items.clone()
     }
-}
 
-/// Creates a `Set<T>` that is the union of two `Set<T>`.
-///
-/// The union of two sets R and S, is the set whose elements
-/// belong either to R or to S or to both.
-///
-/// # Examples
-///
-/// ```rust
-/// # use relations::{Set, union};
-/// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
-/// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
-/// let c = Set::new(&vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
-/// assert_eq!(union(&a, &b), c);
-/// ```
-pub fn union<T: Clone + Ord>(this: &Set<T>, that: &Set<T>) -> Set<T> {
-    let mut v = Vec::new();
-    v.extend_from_slice(this.items.as_slice());
-    v.extend_from_slice(that.items.as_slice());
-    v.sort();
-    v.dedup();
-    Set { items: v }
-}
+    /// Creates an iterator over the objects, in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&vec![0, 1, 2]);
+    /// assert_eq!(s.iter().sum::<i32>(), 3);
+    /// let mut n = 0;
+    /// for x in &s {
+    ///     n += x;
+    /// }
+    /// assert_eq!(n, 3);
+    /// assert_eq!(s.into_iter().count(), 3);
+    /// ```
+    pub fn iter(&self) -> ::std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
 
-/// Creates a `Set<T>` that is the intersection of two `Set<T>`.
-///
-/// The intersection of two sets, R and S, is the set whose elements
-/// belong to both R and S.
-///
-/// # Examples
-///
-/// ```rust
-/// # use relations::{Set, inter};
-/// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
-/// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
-/// let c = Set::new(&vec![4, 5]);
-/// assert_eq!(inter(&a, &b), c);
-/// ```
-pub fn inter<T: Clone + Ord>(this: &Set<T>, that: &Set<T>) -> Set<T> {
-    Set::new(&this.items
-        .iter()
-        .filter(|x| that.has(&x))
-        .map(|x| x.clone())
-        .collect())
-}
+    /// Returns `true` if this `Set<T>` contains exactly one object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// assert!(Set::new(&vec![3]).is_singleton());
+    /// assert!(!Set::new(&vec![3, 4]).is_singleton());
+    /// ```
+    pub fn is_singleton(&self) -> bool {
+        self.items.len() == 1
+    }
 
-/// Creates a `Set<T>` that is the complement of a `Set<T>` relative to
-/// another `Set<T>`.
-///
-/// The complement of set, R, relative to a set, S, is the set whose
-/// elements belong to S but not to R.
-///
-/// ```rust
-/// # use relations::{Set, compl};
-/// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
-/// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
-/// let c = Set::new(&vec![0, 1, 2, 3]);
-/// assert_eq!(compl(&a, &b), c);
-/// ```
-pub fn compl<T: Clone + Ord>(this: &Set<T>, that: &Set<T>) -> Set<T> {
-    Set::new(&this.items
-        .iter()
-        .filter(|x| !that.has(&x))
-        .map(|x| x.clone())
-        .collect())
-}
+    /// Returns the sole object of this `Set<T>` if it contains exactly one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// assert_eq!(Set::new(&vec![3]).as_singleton(), Some(&3));
+    /// assert_eq!(Set::new(&vec![3, 4]).as_singleton(), None);
+    /// ```
+    pub fn as_singleton(&self) -> Option<&T> {
+        if self.is_singleton() {
+            self.items.first()
+        } else {
+            None
+        }
+    }
 
-/// Represents links between objects in a `Set<T>`.
-#[derive(Debug)]
-pub struct Relation<T> {
-    set: Set<T>,
-    links: Vec<(T, T)>,
-}
+    /// Splits this `Set<T>` at a pivot, creating a `Set<T>` of the objects
+    /// less than the pivot and a `Set<T>` of the objects greater than or equal
+    /// to it.
+    ///
+    /// Since the objects are sorted, the split point is found by a binary
+    /// search.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let (a, b) = Set::new(&vec![0, 1, 2, 3, 4]).split_at(&2);
+    /// assert_eq!(a, Set::new(&vec![0, 1]));
+    /// assert_eq!(b, Set::new(&vec![2, 3, 4]));
+    /// ```
+    pub fn split_at(&self, pivot: &T) -> (Set<T>, Set<T>) {
+        let i = self.items.partition_point(|x| x < pivot);
+        (Set { items: self.items[..i].to_vec() }, Set { items: self.items[i..].to_vec() })
+    }
 
-impl<T: Ord + Clone> PartialEq for Relation<T> {
-    fn eq(&self, other: &Relation<T>) -> bool {
-        let ref a = *self.links;
-        if self.links.len() != other.links.len() {
-            return false;
-        }
-        for item in a {
-            if !other.has(item) {
-                return false;
+    /// Creates a new `Set<T>` from the given objects, failing if any object
+    /// appears more than once.
+    ///
+    /// The first repeated object is reported. The objects seen so far are kept
+    /// in a `BTreeSet`, for a cost of O(n log n).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, SetError};
+    /// assert_eq!(Set::try_from_no_dups(vec![0, 1, 2]), Ok(Set::new(&vec![0, 1, 2])));
+    /// assert_eq!(Set::try_from_no_dups(vec![0, 1, 0]), Err(SetError::Duplicate(0)));
+    /// ```
+    pub fn try_from_no_dups<I: IntoIterator<Item = T>>(iter: I) -> Result<Set<T>, SetError<T>> {
+        let mut seen = BTreeSet::new();
+        for item in iter {
+            if !seen.insert(item.clone()) {
+                return Err(SetError::Duplicate(item));
             }
         }
-        return true;
+        Ok(Set { items: seen.into_iter().collect() })
     }
-}
 
-impl<T: Ord + Clone> Relation<T> {
+    /// Creates a `Vec` of every subset of this `Set<T>` with the given size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&vec![0, 1, 2]).subsets_of_size(2);
+    /// assert_eq!(s, vec![Set::new(&vec![0, 1]), Set::new(&vec![0, 2]), Set::new(&vec![1, 2])]);
+    /// assert_eq!(Set::new(&vec![0, 1, 2]).subsets_of_size(0).len(), 1);
+    /// assert!(Set::new(&vec![0, 1, 2]).subsets_of_size(4).is_empty());
+    /// ```
+    pub fn subsets_of_size(&self, k: usize) -> Vec<Set<T>> {
+        let n = self.items.len();
+        let mut v = Vec::new();
+        if k > n {
+            return v;
+        }
+        let mut idx = (0..k).collect::<Vec<usize>>();
+        loop {
+            v.push(Set::new(&idx.iter().map(|&i| self.items[i].clone()).collect::<Vec<T>>()));
+            let mut i = k;
+            while i > 0 && idx[i - 1] == n - k + i - 1 {
+                i -= 1;
+            }
+            if i == 0 {
+                return v;
+            }
+            idx[i - 1] += 1;
+            for j in i..k {
+                idx[j] = idx[j - 1] + 1;
+            }
+        }
+    }
 
-    /// Creates a new `Relation<T>` with a given `Set<T>` and a `Vec` of links.
-    pub fn new(set: &Set<T>, links: &Vec<(T, T)>) -> Relation<T> {
-        Relation {
-            set: set.clone(),
-            links: links.clone(),
+    /// Returns the Jaccard similarity of this `Set<T>` and another `Set<T>`.
+    ///
+    /// This is the size of their intersection divided by the size of their
+    /// union, or `0.0` if both are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let a = Set::new(&vec![0, 1, 2]);
+    /// let b = Set::new(&vec![1, 2, 3]);
+    /// assert_eq!(a.jaccard(&b), 0.5);
+    /// ```
+    pub fn jaccard(&self, other: &Set<T>) -> f64 {
+        let u = union(self, other).len();
+        if u == 0 {
+            return 0.0;
         }
+        inter(self, other).len() as f64 / u as f64
     }
 
-    /// Adds a link of the form `(T, T)`.
-    pub fn add_link(&mut self, l: (T, T)) {
-        if !self.has(&l) && self.set.has(&l.0) && self.set.has(&l.1) {
-            self.links.push(l);
+    /// Returns `true` if every object of this `Set<T>` belongs to the other
+    /// `Set<T>`.
+    ///
+    /// Both sets are walked side by side in sorted order, so the check is
+    /// linear in their sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let a = Set::new(&(0..2000).filter(|x| x % 3 == 0).collect::<Vec<i32>>());
+    /// let b = Set::new(&(0..3000).rev().collect::<Vec<i32>>());
+    /// let c = Set::new(&(0..2000).filter(|x| x % 7 != 0).collect::<Vec<i32>>());
+    /// assert_eq!(a.is_subset_sorted(&b), a.is_subset(&b));
+    /// assert_eq!(a.is_subset_sorted(&c), a.is_subset(&c));
+    /// assert!(a.is_subset_sorted(&b));
+    /// assert!(!a.is_subset_sorted(&c));
+    /// ```
+    pub fn is_subset_sorted(&self, other: &Set<T>) -> bool {
+        let b = &other.items;
+        let mut j = 0;
+        for x in self.items.iter() {
+            while j < b.len() && b[j] < *x {
+                j += 1;
+            }
+            if j == b.len() || b[j] != *x {
+                return false;
+            }
         }
+        true
     }
 
-    /// Adds each link in a `Vec` of links.
-    pub fn add_links(&mut self, ls: Vec<(T, T)>) {
-        for l in ls {
-            if !self.has(&l) && self.set.has(&l.0) && self.set.has(&l.1) {
-                self.add_link(l);
+    /// Creates a `Set<U>` of the results of applying a function to each
+    /// object, keeping only the `Some` values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&vec!["1", "x", "3"]);
+    /// assert_eq!(s.filter_map(|x| x.parse::<i32>().ok()), Set::new(&vec![1, 3]));
+    /// ```
+    pub fn filter_map<U: Ord + Clone, F: Fn(&T) -> Option<U>>(&self, f: F) -> Set<U> {
+        Set::new(&self.items.iter().filter_map(f).collect::<Vec<U>>())
+    }
+
+    /// Creates an iterator over the objects that belong to both this `Set<T>`
+    /// and the other `Set<T>`, without building their intersection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, inter};
+    /// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
+    /// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
+    /// let c = a.intersection_iter(&b).cloned().collect::<Vec<i32>>();
+    /// assert_eq!(Set::new(&c), inter(&a, &b));
+    /// ```
+    pub fn intersection_iter<'a>(&'a self, other: &'a Set<T>) -> impl Iterator<Item = &'a T> + 'a {
+        self.items.iter().filter(move |x| other.has(x))
+    }
+
+    /// Creates a `Set<T>` of the k greatest objects of this `Set<T>`, or of
+    /// all of them if there are no more than k.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// assert_eq!(Set::new(&vec![1, 2, 3, 4, 5]).top_k(2), Set::new(&vec![4, 5]));
+    /// assert_eq!(Set::new(&vec![1, 2]).top_k(3), Set::new(&vec![1, 2]));
+    /// ```
+    pub fn top_k(&self, k: usize) -> Set<T> {
+        let n = self.items.len();
+        Set { items: self.items[n.saturating_sub(k)..].to_vec() }
+    }
+
+    /// Creates an iterator over every subset of this `Set<T>`.
+    ///
+    /// The subsets are built one at a time by counting through the bitmasks
+    /// of the objects, so only the subsets that are consumed are ever built.
+    /// The empty set comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&(0..100).collect::<Vec<i32>>());
+    /// let first = s.powerset_iter().take(4).collect::<Vec<Set<i32>>>();
+    /// assert_eq!(first, vec![Set::new(&vec![]), Set::new(&vec![0]),
+    ///                        Set::new(&vec![1]), Set::new(&vec![0, 1])]);
+    /// assert_eq!(Set::new(&vec![0, 1, 2]).powerset_iter().count(), 8);
+    /// ```
+    pub fn powerset_iter(&self) -> impl Iterator<Item = Set<T>> {
+        let items = self.items.clone();
+        let mut mask = vec![false; items.len()];
+        let mut done = false;
+        ::std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let s = Set::new(&items
+                             .iter()
+                             .zip(mask.iter())
+                             .filter(|x| *x.1)
+                             .map(|x| x.0.clone())
+                             .collect::<Vec<T>>());
+            done = true;
+            for bit in mask.iter_mut() {
+                *bit = !*bit;
+                if *bit {
+                    done = false;
+                    break;
+                }
+            }
+            Some(s)
+        })
+    }
+
+    /// Returns a hash of the objects of this `Set<T>` that is stable across
+    /// program runs.
+    ///
+    /// The objects are fed in sorted order to a 64-bit FNV-1a hasher, which
+    /// has no random seed, so equal sets always give the same value. The value
+    /// still depends on the `Hash` implementation of `T`, which may differ
+    /// between platforms and compiler versions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let a = Set::new(&vec![0, 1, 2]);
+    /// let b = Set::new(&vec![2, 0, 1]);
+    /// assert_eq!(a.stable_hash(), b.stable_hash());
+    /// assert_ne!(a.stable_hash(), Set::new(&vec![0, 1]).stable_hash());
+    /// assert_eq!(Set::new(&vec![1u8, 2u8]).stable_hash(), 11176212247314976144);
+    /// ```
+    pub fn stable_hash(&self) -> u64
+        where T: Hash
+    {
+        let mut h = Fnv64::new();
+        self.items.hash(&mut h);
+        h.finish()
+    }
+
+    /// Creates an iterator over the objects of this `Set<T>` that do not
+    /// belong to the other `Set<T>`, without building their complement.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, compl};
+    /// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
+    /// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
+    /// let c = a.difference_iter(&b).cloned().collect::<Vec<i32>>();
+    /// assert_eq!(Set::new(&c), compl(&a, &b));
+    /// ```
+    pub fn difference_iter<'a>(&'a self, other: &'a Set<T>) -> impl Iterator<Item = &'a T> + 'a {
+        self.items.iter().filter(move |x| !other.has(x))
+    }
+
+    /// Compares two `Set<T>` by their size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let mut v = vec![Set::new(&vec![0, 1, 2]), Set::new(&vec![3]), Set::new(&vec![4, 5])];
+    /// v.sort_by(Set::cmp_by_len);
+    /// assert_eq!(v, vec![Set::new(&vec![3]), Set::new(&vec![4, 5]), Set::new(&vec![0, 1, 2])]);
+    /// ```
+    pub fn cmp_by_len(a: &Set<T>, b: &Set<T>) -> Ordering {
+        a.len().cmp(&b.len())
+    }
+
+    /// Returns `true` if every object of this `Set<T>` belongs to the other
+    /// `Set<T>`.
+    ///
+    /// The objects of the other set are already sorted, so each object is
+    /// found by binary search without copying, for a cost of O(n log m).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let a = Set::new(&vec![1, 2]);
+    /// let b = Set::new(&vec![0, 1, 2, 3]);
+    /// let e = Set::empty();
+    /// assert!(a.is_subset(&b));
+    /// assert!(!b.is_subset(&a));
+    /// assert!(a.is_subset(&a));
+    /// assert!(e.is_subset(&a) && e.is_subset(&e));
+    /// ```
+    pub fn is_subset(&self, other: &Set<T>) -> bool {
+        self.len() <= other.len() && self.items.iter().all(|x| other.items.binary_search(x).is_ok())
+    }
+
+    /// Returns `true` if every object of the other `Set<T>` belongs to this
+    /// `Set<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let a = Set::new(&vec![1, 2]);
+    /// let b = Set::new(&vec![0, 1, 2, 3]);
+    /// assert!(b.is_superset(&a));
+    /// assert!(!a.is_superset(&b));
+    /// assert!(a.is_superset(&a));
+    /// ```
+    pub fn is_superset(&self, other: &Set<T>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if this `Set<T>` and the other `Set<T>` have no object
+    /// in common.
+    ///
+    /// Stops at the first common object found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let a = Set::new(&vec![0, 1, 2]);
+    /// let b = Set::new(&vec![3, 4]);
+    /// let e: Set<i32> = Set::empty();
+    /// assert!(a.is_disjoint(&b));
+    /// assert!(!a.is_disjoint(&Set::new(&vec![2, 3])));
+    /// assert!(!a.is_disjoint(&a));
+    /// assert!(e.is_disjoint(&e));
+    /// ```
+    pub fn is_disjoint(&self, other: &Set<T>) -> bool {
+        !self.items.iter().any(|x| other.has(x))
+    }
+
+    /// Creates a `Relation<T>` linking each object of this `Set<T>` to the
+    /// next greater one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let s = Set::new(&vec![2, 0, 1]);
+    /// assert_eq!(s.consecutive_relation(), Relation::new(&s, &vec![(0, 1), (1, 2)]));
+    /// ```
+    pub fn consecutive_relation(&self) -> Relation<T> {
+        Relation::new(self,
+                      &self.items.windows(2)
+                      .map(|x| (x[0].clone(), x[1].clone()))
+                      .collect::<Vec<(T, T)>>())
+    }
+
+    /// Creates the power set of this `Set<T>`, i.e. the `Set` of all of its
+    /// subsets.
+    ///
+    /// A set of size n has 2^n subsets, so this quickly becomes expensive;
+    /// see `powerset_iter` to build the subsets one at a time instead. The
+    /// subsets are sorted, comparing their sorted objects lexicographically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let p = Set::new(&vec![0, 1]).power_set();
+    /// assert_eq!(p.len(), 4);
+    /// assert_eq!(p.as_vec(), vec![Set::new(&vec![]), Set::new(&vec![0]),
+    ///                             Set::new(&vec![0, 1]), Set::new(&vec![1])]);
+    /// ```
+    pub fn power_set(&self) -> Set<Set<T>> {
+        let mut v = self.powerset_iter().collect::<Vec<Set<T>>>();
+        v.sort();
+        Set { items: v }
+    }
+
+    /// Creates a new `Set<T>` with every value from `start` up to, but not
+    /// including, `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// assert_eq!(Set::from_range(0, 5), Set::new(&vec![0, 1, 2, 3, 4]));
+    /// assert_eq!(Set::from_range(5u8, 5u8).len(), 0);
+    /// ```
+    pub fn from_range(start: T, end: T) -> Set<T>
+        where T: Step
+    {
+        let mut v = Vec::new();
+        let mut x = start;
+        while x < end {
+            let next = x.successor();
+            v.push(x);
+            x = next;
+        }
+        Set { items: v }
+    }
+
+    /// Creates a `Set<T>` that is the intersection of all the given `Set<T>`.
+    ///
+    /// Starts from the smallest set and removes the objects missing from each
+    /// other set in turn, stopping as soon as nothing is left. The
+    /// intersection of no sets is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, inter};
+    /// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
+    /// let b = Set::new(&vec![1, 2, 3, 4]);
+    /// let c = Set::new(&vec![2, 3, 4, 8]);
+    /// assert_eq!(Set::inter_all_into(&[&a, &b, &c]), inter(&inter(&a, &b), &c));
+    /// assert!(Set::inter_all_into(&[&a, &Set::new(&vec![9])]).is_empty());
+    /// ```
+    pub fn inter_all_into(sets: &[&Set<T>]) -> Set<T> {
+        let smallest = match sets.iter().min_by_key(|x| x.len()) {
+            Some(s) => s,
+            None => return Set::empty(),
+        };
+        let mut v = smallest.items.clone();
+        for s in sets.iter() {
+            if v.is_empty() {
+                break;
+            }
+            v.retain(|x| s.has(x));
+        }
+        Set { items: v }
+    }
+
+    /// Adds an object, returning `true` if it was not already present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let mut s = Set::new(&vec![0, 2]);
+    /// assert!(s.insert(1));
+    /// assert!(!s.insert(1));
+    /// assert_eq!(s, Set::new(&vec![0, 1, 2]));
+    /// ```
+    pub fn insert(&mut self, x: T) -> bool {
+        match self.items.binary_search(&x) {
+            Ok(_) => false,
+            Err(i) => {
+                self.items.insert(i, x);
+                true
+            }
+        }
+    }
+
+    /// Removes an object, returning `true` if it was present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let mut s = Set::new(&vec![0, 1, 2]);
+    /// assert!(s.remove(&1));
+    /// assert!(!s.remove(&1));
+    /// assert_eq!(s, Set::new(&vec![0, 2]));
+    /// ```
+    pub fn remove(&mut self, x: &T) -> bool {
+        match self.items.binary_search(x) {
+            Ok(i) => {
+                self.items.remove(i);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Folds the objects of this `Set<T>` into a single value, visiting them
+    /// in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// assert_eq!(Set::new(&vec![1, 2, 3, 4]).reduce(1, |a, x| a * x), 24);
+    /// ```
+    pub fn reduce<A, F: Fn(A, &T) -> A>(&self, init: A, f: F) -> A {
+        self.items.iter().fold(init, f)
+    }
+
+    /// Returns `true` if every object of the iterator belongs to this
+    /// `Set<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&vec![0, 1, 2, 3]);
+    /// assert!(s.contains_all(vec![1, 3]));
+    /// assert!(!s.contains_all(vec![1, 4]));
+    /// assert!(s.contains_all(Vec::new()));
+    /// ```
+    pub fn contains_all<I: IntoIterator<Item = T>>(&self, items: I) -> bool {
+        items.into_iter().all(|x| self.has(&x))
+    }
+
+    /// Creates a new `Set<T>` of the objects of a universe whose mask entry
+    /// is `true`.
+    ///
+    /// The object `universe[i]` is kept if `mask[i]` is `true`. If the two
+    /// slices differ in length, the longer one is truncated to the shorter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// assert_eq!(Set::from_mask(&[0, 1, 2], &[true, false, true]), Set::new(&vec![0, 2]));
+    /// assert_eq!(Set::from_mask(&[0, 1, 2], &[false, true]), Set::new(&vec![1]));
+    /// ```
+    pub fn from_mask(universe: &[T], mask: &[bool]) -> Set<T> {
+        Set::new(&universe.iter()
+                 .zip(mask.iter())
+                 .filter(|x| *x.1)
+                 .map(|x| x.0.clone())
+                 .collect::<Vec<T>>())
+    }
+
+    /// Creates a `Set<U>` of the results of applying a function to each
+    /// object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&vec![0, 1, 2, 3, 4]);
+    /// assert_eq!(s.map(|x| x % 3), Set::new(&vec![0, 1, 2]));
+    /// ```
+    pub fn map<U: Ord + Clone, F: Fn(&T) -> U>(&self, f: F) -> Set<U> {
+        Set::new(&self.items.iter().map(f).collect::<Vec<U>>())
+    }
+
+    /// Creates a `Set<T>` of the objects satisfying a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&vec![0, 1, 2, 3, 4]);
+    /// assert_eq!(s.filter(|x| *x > 2), Set::new(&vec![3, 4]));
+    /// ```
+    pub fn filter<F: Fn(&T) -> bool>(&self, pred: F) -> Set<T> {
+        Set { items: self.items.iter().filter(|x| pred(x)).cloned().collect() }
+    }
+
+    /// Splits this `Set<T>` into a `Set<T>` of the objects satisfying a
+    /// predicate and a `Set<T>` of the others.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, union};
+    /// let s = Set::new(&vec![0, 1, 2, 3, 4]);
+    /// let (even, odd) = s.partition(|x| x % 2 == 0);
+    /// assert_eq!(even, Set::new(&vec![0, 2, 4]));
+    /// assert_eq!(odd, Set::new(&vec![1, 3]));
+    /// assert_eq!(union(&even, &odd), s);
+    /// assert!(even.is_disjoint(&odd));
+    /// ```
+    pub fn partition<F: Fn(&T) -> bool>(&self, pred: F) -> (Set<T>, Set<T>) {
+        let (a, b) = self.items.iter().cloned().partition(|x| pred(x));
+        (Set { items: a }, Set { items: b })
+    }
+
+    /// Creates a `vec` whose entry `i` is `true` if `universe[i]` belongs to
+    /// this `Set<T>`.
+    ///
+    /// This is the inverse of `Set::from_mask` over the same universe.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let u = [0, 1, 2, 3];
+    /// let s = Set::new(&vec![1, 3, 5]);
+    /// assert_eq!(s.membership_vector(&u), vec![false, true, false, true]);
+    /// assert_eq!(Set::from_mask(&u, &s.membership_vector(&u)), Set::new(&vec![1, 3]));
+    /// ```
+    pub fn membership_vector(&self, universe: &[T]) -> Vec<bool> {
+        universe.iter().map(|u| self.has(u)).collect()
+    }
+
+    /// Creates a `Vec` of pairs of each object with the key given to it by a
+    /// function.
+    ///
+    /// The pairs follow the order of the objects. They can be used as the
+    /// links between the objects and their keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::Set;
+    /// let s = Set::new(&vec![3, 1, 2, 4]);
+    /// let p = s.classify_pairs(|x| if x % 2 == 0 { "even" } else { "odd" });
+    /// assert_eq!(p, vec![(1, "odd"), (2, "even"), (3, "odd"), (4, "even")]);
+    /// ```
+    pub fn classify_pairs<K: Ord + Clone, F: Fn(&T) -> K>(&self, key: F) -> Vec<(T, K)> {
+        self.items.iter().map(|x| (x.clone(), key(x))).collect()
+    }
+}
+
+/// Represents types whose values each have a next value, such as integers.
+pub trait Step: Ord + Clone {
+    /// Returns the value following this one.
+    fn successor(&self) -> Self;
+}
+
+macro_rules! impl_step {
+    ($($t:ty)*) => ($(
+        impl Step for $t {
+            fn successor(&self) -> $t {
+                *self + 1
+            }
+        }
+    )*)
+}
+
+impl_step! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+/// Creates a `Set<T>` that is the union of two `Set<T>`.
+///
+/// The union of two sets R and S, is the set whose elements
+/// belong either to R or to S or to both.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, union};
+/// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
+/// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
+/// let c = Set::new(&vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// assert_eq!(union(&a, &b), c);
+/// ```
+pub fn union<T: Clone + Ord>(this: &Set<T>, that: &Set<T>) -> Set<T> {
+    let mut v = Vec::new();
+    v.extend_from_slice(this.items.as_slice());
+    v.extend_from_slice(that.items.as_slice());
+    v.sort();
+    v.dedup();
+    Set { items: v }
+}
+
+/// Creates a `Set<T>` that is the intersection of two `Set<T>`.
+///
+/// The intersection of two sets, R and S, is the set whose elements
+/// belong to both R and S.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, inter};
+/// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
+/// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
+/// let c = Set::new(&vec![4, 5]);
+/// assert_eq!(inter(&a, &b), c);
+/// ```
+pub fn inter<T: Clone + Ord>(this: &Set<T>, that: &Set<T>) -> Set<T> {
+    Set::new(&this.items
+        .iter()
+        .filter(|x| that.has(x))
+        .cloned()
+        .collect())
+}
+
+/// Creates a `Set<T>` that is the complement of a `Set<T>` relative to
+/// another `Set<T>`.
+///
+/// The complement of set, R, relative to a set, S, is the set whose
+/// elements belong to S but not to R.
+///
+/// ```rust
+/// # use relations::{Set, compl};
+/// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
+/// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
+/// let c = Set::new(&vec![0, 1, 2, 3]);
+/// assert_eq!(compl(&a, &b), c);
+/// ```
+pub fn compl<T: Clone + Ord>(this: &Set<T>, that: &Set<T>) -> Set<T> {
+    Set::new(&this.items
+        .iter()
+        .filter(|x| !that.has(x))
+        .cloned()
+        .collect())
+}
+
+/// Creates the union of two `Set<T>` with `|`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, union};
+/// let a = Set::new(&vec![0, 1, 2]);
+/// let b = Set::new(&vec![2, 3]);
+/// assert_eq!(&a | &b, union(&a, &b));
+/// ```
+impl<T: Clone + Ord> BitOr<&Set<T>> for &Set<T> {
+    type Output = Set<T>;
+
+    fn bitor(self, that: &Set<T>) -> Set<T> {
+        union(self, that)
+    }
+}
+
+impl<T: Clone + Ord> BitOr<&Set<T>> for Set<T> {
+    type Output = Set<T>;
+
+    fn bitor(self, that: &Set<T>) -> Set<T> {
+        union(&self, that)
+    }
+}
+
+/// Creates the intersection of two `Set<T>` with `&`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, inter};
+/// let a = Set::new(&vec![0, 1, 2]);
+/// let b = Set::new(&vec![2, 3]);
+/// let c = Set::new(&vec![3, 4]);
+/// assert_eq!(&a & &b, inter(&a, &b));
+/// assert_eq!(&a & &b | &c, Set::new(&vec![2, 3, 4]));
+/// assert_eq!(&a - &b - &c, Set::new(&vec![0, 1]));
+/// ```
+impl<T: Clone + Ord> BitAnd<&Set<T>> for &Set<T> {
+    type Output = Set<T>;
+
+    fn bitand(self, that: &Set<T>) -> Set<T> {
+        inter(self, that)
+    }
+}
+
+impl<T: Clone + Ord> BitAnd<&Set<T>> for Set<T> {
+    type Output = Set<T>;
+
+    fn bitand(self, that: &Set<T>) -> Set<T> {
+        inter(&self, that)
+    }
+}
+
+/// Creates the complement of a `Set<T>` relative to another with `-`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, compl};
+/// let a = Set::new(&vec![0, 1, 2]);
+/// let b = Set::new(&vec![2, 3]);
+/// assert_eq!(&a - &b, compl(&a, &b));
+/// ```
+impl<T: Clone + Ord> Sub<&Set<T>> for &Set<T> {
+    type Output = Set<T>;
+
+    fn sub(self, that: &Set<T>) -> Set<T> {
+        compl(self, that)
+    }
+}
+
+impl<T: Clone + Ord> Sub<&Set<T>> for Set<T> {
+    type Output = Set<T>;
+
+    fn sub(self, that: &Set<T>) -> Set<T> {
+        compl(&self, that)
+    }
+}
+
+/// Creates a `Set<T>` that is the symmetric difference of two `Set<T>`.
+///
+/// The symmetric difference of two sets, R and S, is the set whose elements
+/// belong to exactly one of R and S.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, sym_diff, union};
+/// let a = Set::new(&vec![0, 1, 2, 3, 4, 5]);
+/// let b = Set::new(&vec![4, 5, 6, 7, 8, 9]);
+/// let c = Set::new(&vec![0, 1, 2, 3, 6, 7, 8, 9]);
+/// assert_eq!(sym_diff(&a, &b), c);
+/// let d = Set::new(&vec![10, 11]);
+/// assert_eq!(sym_diff(&a, &d), union(&a, &d));
+/// assert_eq!(sym_diff(&a, &a).len(), 0);
+/// ```
+pub fn sym_diff<T: Clone + Ord>(this: &Set<T>, that: &Set<T>) -> Set<T> {
+    let mut v = Vec::new();
+    v.extend(this.items.iter().filter(|x| !that.has(x)).cloned());
+    v.extend(that.items.iter().filter(|x| !this.has(x)).cloned());
+    v.sort();
+    v.dedup();
+    Set { items: v }
+}
+
+/// Creates a `Set<(T, T)>` that is the Cartesian product of two `Set<T>`.
+///
+/// The Cartesian product of two sets, R and S, is the set of all ordered
+/// pairs (r, s) with r in R and s in S. The product of a set with itself
+/// holds every possible link on that set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, product};
+/// let a = Set::new(&vec![0, 1]);
+/// let b = Set::new(&vec![2, 3, 4]);
+/// let c = product(&a, &b);
+/// assert_eq!(c.len(), a.len() * b.len());
+/// assert_eq!(c, Set::new(&vec![(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)]));
+/// ```
+pub fn product<T: Clone + Ord>(this: &Set<T>, that: &Set<T>) -> Set<(T, T)> {
+    let mut v = Vec::new();
+    for a in this.items.iter() {
+        for b in that.items.iter() {
+            v.push((a.clone(), b.clone()));
+        }
+    }
+    v.sort();
+    v.dedup();
+    Set { items: v }
+}
+
+/// Creates a `Set<(usize, T)>` that is the disjoint union of two `Set<T>`.
+///
+/// Every object is tagged with the set it comes from, 0 for the first and 1
+/// for the second, so that objects belonging to both remain distinct.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, disjoint_union};
+/// let a = Set::new(&vec![0, 1, 2]);
+/// let b = Set::new(&vec![2, 3]);
+/// let c = disjoint_union(&a, &b);
+/// assert_eq!(c.len(), a.len() + b.len());
+/// assert!(c.has(&(0, 2)) && c.has(&(1, 2)));
+/// ```
+pub fn disjoint_union<T: Clone + Ord>(a: &Set<T>, b: &Set<T>) -> Set<(usize, T)> {
+    let mut v = Vec::new();
+    v.extend(a.items.iter().map(|x| (0, x.clone())));
+    v.extend(b.items.iter().map(|x| (1, x.clone())));
+    Set::new(&v)
+}
+
+/// A 64-bit FNV-1a `Hasher` with a fixed starting state.
+///
+/// Integers are fed as little-endian bytes, with `usize` and `isize` widened
+/// to 64 bits, so that hashes agree across platforms.
+struct Fnv64(u64);
+
+impl Fnv64 {
+    fn new() -> Fnv64 {
+        Fnv64(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv64 {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= u64::from(*b);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, n: u128) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write(&(n as u64).to_le_bytes());
+    }
+
+    fn write_isize(&mut self, n: isize) {
+        self.write(&(n as i64).to_le_bytes());
+    }
+}
+
+/// Creates a `Set<(T, T)>` containing every pair of a `Set<(T, T)>` along
+/// with its reverse.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, symmetrize_pairs};
+/// let a = Set::new(&vec![(0, 1)]);
+/// assert_eq!(symmetrize_pairs(&a), Set::new(&vec![(0, 1), (1, 0)]));
+/// ```
+pub fn symmetrize_pairs<T: Clone + Ord>(pairs: &Set<(T, T)>) -> Set<(T, T)> {
+    let mut v = Vec::new();
+    for item in pairs.items.iter() {
+        v.push(item.clone());
+        v.push((item.1.clone(), item.0.clone()));
+    }
+    Set::new(&v)
+}
+
+/// Represents a discrete set of small non-negative integers as a bitmap.
+///
+/// Membership takes constant time, and union, intersection and complement
+/// work a word of 64 objects at a time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, IntSet, union, inter, compl};
+/// let a = Set::new(&vec![0, 1, 2, 3, 64, 130]);
+/// let b = Set::new(&vec![3, 4, 64, 200]);
+/// let (x, y) = (IntSet::from(&a), IntSet::from(&b));
+/// assert_eq!(x.len(), a.len());
+/// assert!(x.has(&130) && !x.has(&131));
+/// assert_eq!(Set::new(&x.union(&y).as_vec()), union(&a, &b));
+/// assert_eq!(Set::new(&x.inter(&y).as_vec()), inter(&a, &b));
+/// assert_eq!(Set::new(&x.compl(&y).as_vec()), compl(&a, &b));
+/// assert_eq!(x.inter(&y), IntSet::from(&Set::new(&vec![3, 64])));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntSet {
+    words: Vec<u64>,
+}
+
+impl IntSet {
+    /// Creates a new, empty `IntSet`.
+    pub fn new() -> IntSet {
+        IntSet { words: Vec::new() }
+    }
+
+    /// Adds an object, returning `true` if it was not already present.
+    pub fn insert(&mut self, x: usize) -> bool {
+        if self.words.len() <= x / 64 {
+            self.words.resize(x / 64 + 1, 0);
+        }
+        let had = self.has(&x);
+        self.words[x / 64] |= 1 << (x % 64);
+        !had
+    }
+
+    /// Returns `true` if this `IntSet` contains the given object.
+    pub fn has(&self, x: &usize) -> bool {
+        match self.words.get(x / 64) {
+            Some(w) => w & (1 << (x % 64)) != 0,
+            None => false,
+        }
+    }
+
+    /// Returns the size of this `IntSet`.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if this `IntSet` contains no objects.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    /// Creates a sorted `vec` containing the objects.
+    pub fn as_vec(&self) -> Vec<usize> {
+        let mut v = Vec::new();
+        for (i, w) in self.words.iter().enumerate() {
+            for j in 0..64 {
+                if w & (1 << j) != 0 {
+                    v.push(i * 64 + j);
+                }
+            }
+        }
+        v
+    }
+
+    /// Creates an `IntSet` that is the union of two `IntSet`.
+    pub fn union(&self, other: &IntSet) -> IntSet {
+        let n = self.words.len().max(other.words.len());
+        IntSet {
+            words: (0..n)
+                .map(|i| self.word(i) | other.word(i))
+                .collect(),
+        }
+    }
+
+    /// Creates an `IntSet` that is the intersection of two `IntSet`.
+    pub fn inter(&self, other: &IntSet) -> IntSet {
+        IntSet {
+            words: (0..self.words.len())
+                .map(|i| self.word(i) & other.word(i))
+                .collect(),
+        }
+    }
+
+    /// Creates an `IntSet` of the objects of this `IntSet` that do not belong
+    /// to the other `IntSet`.
+    pub fn compl(&self, other: &IntSet) -> IntSet {
+        IntSet {
+            words: (0..self.words.len())
+                .map(|i| self.word(i) & !other.word(i))
+                .collect(),
+        }
+    }
+
+    /// Returns the word of the bitmap at the given index, or 0 past its end.
+    fn word(&self, i: usize) -> u64 {
+        self.words.get(i).cloned().unwrap_or(0)
+    }
+}
+
+impl PartialEq for IntSet {
+    fn eq(&self, other: &IntSet) -> bool {
+        let n = self.words.len().max(other.words.len());
+        (0..n).all(|i| self.word(i) == other.word(i))
+    }
+}
+
+impl Default for IntSet {
+    fn default() -> IntSet {
+        IntSet::new()
+    }
+}
+
+impl<'a> From<&'a Set<usize>> for IntSet {
+    fn from(set: &'a Set<usize>) -> IntSet {
+        let mut s = IntSet::new();
+        for x in set.items.iter() {
+            s.insert(*x);
+        }
+        s
+    }
+}
+
+/// Represents a set of objects that can be hashed but need not be ordered.
+///
+/// Membership takes constant time on average. The order in which the objects
+/// are visited by `iter` is unspecified and may differ between two equal
+/// `HashedSet<T>`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::HashedSet;
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// struct Colour(&'static str);
+///
+/// let a = HashedSet::new(&[Colour("red"), Colour("green"), Colour("red")]);
+/// let b = HashedSet::new(&[Colour("green"), Colour("blue")]);
+/// assert_eq!(a.len(), 2);
+/// assert!(a.has(&Colour("red")) && !a.has(&Colour("blue")));
+/// assert_eq!(a.union(&b).len(), 3);
+/// assert_eq!(a.inter(&b), HashedSet::new(&[Colour("green")]));
+/// assert_eq!(a.compl(&b), HashedSet::new(&[Colour("red")]));
+/// assert!(a.compl(&a).is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashedSet<T: Eq + Hash> {
+    items: HashSet<T>,
+}
+
+impl<T: Eq + Hash + Clone> HashedSet<T> {
+    /// Creates a `HashedSet<T>` from the given objects, ignoring repeats.
+    pub fn new(items: &[T]) -> HashedSet<T> {
+        HashedSet { items: items.iter().cloned().collect() }
+    }
+
+    /// Returns `true` if this `HashedSet<T>` contains the given object.
+    pub fn has(&self, item: &T) -> bool {
+        self.items.contains(item)
+    }
+
+    /// Returns the size of this `HashedSet<T>`.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if this `HashedSet<T>` contains no objects.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns an iterator over the objects, in unspecified order.
+    pub fn iter(&self) -> ::std::collections::hash_set::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Creates a `HashedSet<T>` that is the union of two `HashedSet<T>`.
+    pub fn union(&self, other: &HashedSet<T>) -> HashedSet<T> {
+        HashedSet { items: self.items.union(&other.items).cloned().collect() }
+    }
+
+    /// Creates a `HashedSet<T>` that is the intersection of two
+    /// `HashedSet<T>`.
+    pub fn inter(&self, other: &HashedSet<T>) -> HashedSet<T> {
+        HashedSet { items: self.items.intersection(&other.items).cloned().collect() }
+    }
+
+    /// Creates a `HashedSet<T>` of the objects of this `HashedSet<T>` that do
+    /// not belong to the other `HashedSet<T>`.
+    pub fn compl(&self, other: &HashedSet<T>) -> HashedSet<T> {
+        HashedSet { items: self.items.difference(&other.items).cloned().collect() }
+    }
+}
+
+/// Represents links between objects in a `Set<T>`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, Relation};
+/// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1)]);
+/// let mut q = r.clone();
+/// q.add_link((1, 2));
+/// assert!(q.has(&(1, 2)));
+/// assert!(!r.has(&(1, 2)));
+/// assert_eq!(r, Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1)]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Relation<T> {
+    set: Set<T>,
+    links: Vec<(T, T)>,
+}
+
+/// Selects the carrier `Set<T>` used when merging two `Relation<T>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CarrierPolicy {
+    /// The union of both carriers.
+    Union,
+    /// The intersection of both carriers.
+    Intersection,
+    /// The carrier of the left-hand `Relation<T>`.
+    LeftOnly,
+    /// The carrier of the right-hand `Relation<T>`.
+    RightOnly,
+}
+
+/// Selects one of the closures of a `Relation<T>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClosureKind {
+    /// The reflexive closure, see `Relation::refl_closure`.
+    Reflexive,
+    /// The symmetric closure, see `Relation::sym_closure`.
+    Symmetric,
+    /// The transitive closure, see `Relation::trans_closure`.
+    Transitive,
+}
+
+/// Represents the ways in which an operation on a `Relation<T>` can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelationError<T> {
+    /// The given object is the tail of more than one link.
+    MultipleParents(T),
+}
+
+impl<T: Ord + Clone> PartialEq for Relation<T> {
+    fn eq(&self, other: &Relation<T>) -> bool {
+        if self.links.len() != other.links.len() {
+            return false;
+        }
+        for item in &self.links {
+            if !other.has(item) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<T: Ord + Clone> Relation<T> {
+
+    /// Creates a new `Relation<T>` with a given `Set<T>` and a `Vec` of links.
+    #[allow(clippy::ptr_arg)]
+    pub fn new(set: &Set<T>, links: &Vec<(T, T)>) -> Relation<T> {
+        Relation {
+            set: set.clone(),
+            links: links.clone(),
+        }
+    }
+
+    /// Adds a link of the form `(T, T)`.
+    pub fn add_link(&mut self, l: (T, T)) {
+        if !self.has(&l) && self.set.has(&l.0) && self.set.has(&l.1) {
+            self.links.push(l);
+        }
+    }
+
+    /// Adds each link in a `Vec` of links.
+    pub fn add_links(&mut self, ls: Vec<(T, T)>) {
+        for l in ls {
+            if !self.has(&l) && self.set.has(&l.0) && self.set.has(&l.1) {
+                self.add_link(l);
+            }
+        }
+    }
+
+    /// Adds an object to the set without linking it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let mut r = Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1)]);
+    /// r.add_link((1, 2));
+    /// assert!(!r.has(&(1, 2)));
+    /// r.add_node(2);
+    /// assert_eq!(r.degree(&2), 0);
+    /// r.add_link((1, 2));
+    /// assert!(r.has(&(1, 2)));
+    /// ```
+    pub fn add_node(&mut self, v: T) {
+        self.set.insert(v);
+    }
+
+    /// Adds each object of an iterator to the set without linking it.
+    pub fn add_nodes<I: IntoIterator<Item = T>>(&mut self, vs: I) {
+        for v in vs {
+            self.add_node(v);
+        }
+    }
+
+    /// Adds a link for each `true` cell of an adjacency matrix.
+    ///
+    /// The cell `m[i][j]` stands for the link (elements[i], elements[j]), and
+    /// is added like any other link with `add_link`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let mut r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![]);
+    /// r.add_from_matrix(&[0, 1, 2], &[vec![false, true, false],
+    ///                                 vec![false, false, true],
+    ///                                 vec![true, false, false]]);
+    /// assert_eq!(r, Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)]));
+    /// ```
+    pub fn add_from_matrix(&mut self, elements: &[T], m: &[Vec<bool>]) {
+        for (x, row) in elements.iter().zip(m.iter()) {
+            for (y, cell) in elements.iter().zip(row.iter()) {
+                if *cell {
+                    self.add_link((x.clone(), y.clone()));
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if this `Relation<T>` contains the given link.
+    pub fn has(&self, l: &(T, T)) -> bool {
+        self.links.iter().any(|x| x == l)
+    }
+
+    /// Returns the number of links in this `Relation<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// assert_eq!(r.link_count(), 2);
+    /// ```
+    pub fn link_count(&self) -> usize {
+        self.links.len()
+    }
+
+    /// Returns `true` if this `Relation<T>` contains no links.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![]).is_empty());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1)]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+
+    /// Creates a `Set<T>` containing all objects to which the given object
+    /// is linked.
+    pub fn neighbours(&self, v: &T) -> Set<T> {
+        union(&self.links_to(v), &self.links_from(v))
+    }
+
+    /// Creates a `Set<T>` containing all objects to which the given object reaches.
+    pub fn links_to(&self, v: &T) -> Set<T> {
+        let mut a = Vec::new();
+        for item in self.links.iter() {
+            if item.0 == *v {
+                a.push(item.1.clone());
+            }
+        }
+        Set::new(&a)
+    }
+
+    /// Creates a `Set<T>` containing all objects from which the given object is
+    /// reachable.
+    pub fn links_from(&self, v: &T) -> Set<T> {
+        let mut a = Vec::new();
+        for item in self.links.iter() {
+            if item.1 == *v {
+                a.push(item.0.clone());
+            }
+        }
+        Set::new(&a)
+    }
+
+    /// Returns the number of objects to which the given object is linked.
+    pub fn degree(&self, v: &T) -> usize {
+        self.neighbours(v).len()
+    }
+
+    /// Creates a `Set<T>` containing all objects that are the root of at
+    /// least one link.
+    pub fn domain(&self) -> Set<T> {
+        let a = self.links.iter().map(|x| x.0.clone()).collect::<Vec<T>>();
+        Set::new(&a)
+    }
+
+    /// Creates a `Set<T>` containing all objects that are the tail of at
+    /// least one link.
+    pub fn codomain(&self) -> Set<T> {
+        let a = self.links.iter().map(|x| x.1.clone()).collect::<Vec<T>>();
+        Set::new(&a)
+    }
+
+    /// Returns `true` if the `Relation<T>` is reflexive.
+    ///
+    /// A `Relation<T>` is said to be reflexive on a set if it contains the link
+    /// (x, x) for all x in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 1)])
+    ///        .is_reflexive());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0)])
+    ///        .is_reflexive());
+    /// ```
+    pub fn is_reflexive(&self) -> bool {
+        self.set.items.iter().all(|x| self.has(&(x.clone(), x.clone())))
+    }
+
+    /// Returns `true` if the `Relation<T>` is irreflexive.
+    ///
+    /// A `Relation<T>` is said to be irreflexive on a set if it contains no
+    /// link (x, x) for any x in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1), (1, 0)])
+    ///        .is_irreflexive());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1)])
+    ///        .is_irreflexive());
+    /// ```
+    pub fn is_irreflexive(&self) -> bool {
+        self.set.items.iter().all(|x| !self.has(&(x.clone(), x.clone())))
+    }
+
+    /// Returns `true` if the `Relation<T>` is symmetric.
+    ///
+    /// A `Relation<T>` is said to be symmetric on a set if it contains the link
+    /// (x, y) for all (y, x).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0)])
+    ///         .is_symmetric());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 1)])
+    ///         .is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        self.links.iter().all(|x| self.has(&(x.1.clone(), x.0.clone())))
+    }
+
+    /// Returns `true` if the `Relation<T>` is antisymmetric.
+    ///
+    /// A `Relation<T>` is said to be antisymmetric on a set if it contains
+    /// both (x, y) and (y, x) only when x and y are the same object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 1)])
+    ///         .is_antisymmetric());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1), (1, 0)])
+    ///         .is_antisymmetric());
+    /// ```
+    pub fn is_antisymmetric(&self) -> bool {
+        self.links.iter().all(|x| x.0 == x.1 || !self.has(&(x.1.clone(), x.0.clone())))
+    }
+
+    /// Returns `true` if the `Relation<T>` is asymmetric.
+    ///
+    /// A `Relation<T>` is said to be asymmetric on a set if it never contains
+    /// both (x, y) and (y, x). In particular it contains no link (x, x).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1)])
+    ///         .is_asymmetric());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1), (1, 0)])
+    ///         .is_asymmetric());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0)])
+    ///         .is_asymmetric());
+    /// ```
+    pub fn is_asymmetric(&self) -> bool {
+        self.links.iter().all(|x| !self.has(&(x.1.clone(), x.0.clone())))
+    }
+
+    /// Returns `true` if the `Relation<T>` is transitive.
+    ///
+    /// A `Relation<T>` is said to be transitive on a set if it contains the
+    /// link (x, z) for all (x, y) and (y, z).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0), (1, 1)])
+    ///         .is_transitive());
+    /// assert!(Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 0), (0, 1), (2, 1)])
+    ///         .is_transitive());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0)])
+    ///         .is_transitive());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)])
+    ///         .is_transitive());
+    /// ```
+    pub fn is_transitive(&self) -> bool {
+        self.links.iter().all(|x| {
+            self.links
+                .iter()
+                .filter(|y| y.0 == x.1)
+                .all(|y| self.has(&(x.0.clone(), y.1.clone())))
+        })
+    }
+
+    /// Creates the `Relation<T>` transitive closure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1)]);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1), (1, 1), (2, 2), (3, 3)]);
+    /// assert_eq!(r.refl_closure(), q);
+    /// ```
+    pub fn refl_closure(&self) -> Relation<T> {
+        rel_union(self, &diagonal(&self.set))
+    }
+
+    /// Creates the `Relation<T>` transitive closure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1)]);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1), (0, 3), (2, 3)]);
+    /// assert_eq!(r.trans_closure(), q);
+    /// let chain = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]);
+    /// assert!(chain.trans_closure().has(&(0, 3)));
+    /// assert!(chain.trans_closure().is_transitive());
+    /// ```
+    pub fn trans_closure(&self) -> Relation<T> {
+        let mut v = Relation::new(&self.set, &self.links);
+        for k in self.set.items.iter() {
+            for i in self.set.items.iter() {
+                for j in self.set.items.iter() {
+                    if !v.has(&(i.clone(), j.clone())) && v.has(&(i.clone(), k.clone())) &&
+                        v.has(&(k.clone(), j.clone())) {
+                            v.links.push((i.clone(), j.clone()));
+                        }
+                }
+            }
+        }
+        v
+    }
+
+    /// Creates the `Relation<T>` symmetric closure.
+    ///
+    /// The symmetric closure is generated by adding the link (y, x) for all
+    /// (x, y) already in the `Relation<T>`.
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1)]);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1), (1, 0), (3, 1), (1, 2)]);
+    /// assert_eq!(r.sym_closure(), q);
+    /// ```
+    pub fn sym_closure(&self) -> Relation<T> {
+        let mut v = Vec::new();
+        v.extend_from_slice(self.links.as_slice());
+        let mut v = Relation::new(&self.set, &v);
+        for i in self.links.iter() {
+            v.add_link((i.1.clone(), i.0.clone()));
+        }
+        v
+    }
+
+    /// Creates a `Relation<T>` whose links are the union of the links of both
+    /// `Relation<T>`, over a carrier chosen by the given `CarrierPolicy`.
+    ///
+    /// Links with an endpoint outside of the chosen carrier are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation, CarrierPolicy};
+    /// let a = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// let b = Relation::new(&Set::new(&vec![1, 2, 3]), &vec![(1, 2), (2, 3)]);
+    /// assert_eq!(a.merge_with(&b, CarrierPolicy::Union),
+    ///            Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]));
+    /// assert_eq!(a.merge_with(&b, CarrierPolicy::Intersection),
+    ///            Relation::new(&Set::new(&vec![1, 2]), &vec![(1, 2)]));
+    /// assert_eq!(a.merge_with(&b, CarrierPolicy::LeftOnly),
+    ///            Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]));
+    /// assert_eq!(a.merge_with(&b, CarrierPolicy::RightOnly),
+    ///            Relation::new(&Set::new(&vec![1, 2, 3]), &vec![(1, 2), (2, 3)]));
+    /// ```
+    pub fn merge_with(&self, other: &Relation<T>, carrier: CarrierPolicy) -> Relation<T> {
+        let set = match carrier {
+            CarrierPolicy::Union => union(&self.set, &other.set),
+            CarrierPolicy::Intersection => inter(&self.set, &other.set),
+            CarrierPolicy::LeftOnly => self.set.clone(),
+            CarrierPolicy::RightOnly => other.set.clone(),
+        };
+        let mut v = Vec::new();
+        v.extend_from_slice(self.links.as_slice());
+        v.extend_from_slice(other.links.as_slice());
+        v.retain(|x| set.has(&x.0) && set.has(&x.1));
+        v.sort();
+        v.dedup();
+        Relation::new(&set, &v)
+    }
+
+    /// Reverses every link of this `Relation<T>` in place.
+    ///
+    /// Each link (x, y) becomes (y, x), and the links are kept sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// let mut q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// q.transpose_in_place();
+    /// assert_eq!(q, Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(1, 0), (2, 1)]));
+    /// q.transpose_in_place();
+    /// assert_eq!(q, r);
+    /// ```
+    pub fn transpose_in_place(&mut self) {
+        for l in self.links.iter_mut() {
+            ::std::mem::swap(&mut l.0, &mut l.1);
+        }
+        self.links.sort();
+    }
+
+    /// Creates the converse of this `Relation<T>` over the same set.
+    ///
+    /// Each link (x, y) becomes (y, x).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// assert_eq!(r.converse(), Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(1, 0), (2, 1)]));
+    /// assert_eq!(r.converse().links_to(&1), r.links_from(&1));
+    /// let s = Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1), (1, 0)]);
+    /// assert_eq!(s.converse(), s);
+    /// ```
+    pub fn converse(&self) -> Relation<T> {
+        let mut r = self.clone();
+        r.transpose_in_place();
+        r
+    }
+
+    /// Returns `true` if the `Relation<T>` is weakly connected.
+    ///
+    /// A `Relation<T>` is said to be weakly connected if, ignoring the
+    /// direction of its links, every object of a non-empty set can be reached
+    /// from every other object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (2, 1)])
+    ///         .is_weakly_connected());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1)])
+    ///         .is_weakly_connected());
+    /// ```
+    pub fn is_weakly_connected(&self) -> bool {
+        match self.set.items.first() {
+            Some(x) => {
+                let c = self.component(x);
+                self.set.items.iter().all(|y| c.contains(y))
+            }
+            None => false,
+        }
+    }
+
+    /// Creates a `Vec` of all objects reachable from the given object when
+    /// the direction of the links is ignored, starting with the object itself.
+    fn component(&self, v: &T) -> Vec<T> {
+        let mut seen = vec![v.clone()];
+        let mut i = 0;
+        while i < seen.len() {
+            for item in self.neighbours(&seen[i]).items {
+                if !seen.contains(&item) {
+                    seen.push(item);
+                }
+            }
+            i += 1;
+        }
+        seen
+    }
+
+    /// Creates the `Relation<T>` symmetric closure without self-loops.
+    ///
+    /// This is the simple undirected graph underlying the `Relation<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]),
+    /// &vec![(0, 0), (0, 1), (2, 1)]);
+    /// let q = r.simple_undirected();
+    /// assert!(q.is_symmetric());
+    /// assert!(!q.has(&(0, 0)));
+    /// assert_eq!(q, Relation::new(&Set::new(&vec![0, 1, 2]),
+    /// &vec![(0, 1), (1, 0), (1, 2), (2, 1)]));
+    /// ```
+    pub fn simple_undirected(&self) -> Relation<T> {
+        let mut v = self.sym_closure();
+        v.links.retain(|x| x.0 != x.1);
+        v
+    }
+
+    /// Returns the number of triangles in the `Relation<T>`.
+    ///
+    /// A triangle is a set of three distinct objects that are all linked to
+    /// each other, ignoring the direction of the links. Each triangle is
+    /// counted once by intersecting the neighbours of its smallest object in
+    /// adjacency lists built once, which takes O(E * log E + n * d² * log d)
+    /// for a maximum degree d.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)]);
+    /// assert_eq!(r.triangle_count(), 1);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// assert_eq!(q.triangle_count(), 0);
+    /// ```
+    pub fn triangle_count(&self) -> usize {
+        let (_, adj) = self.undirected_adjacency();
+        let mut count = 0;
+        for (a, n) in adj.iter().enumerate() {
+            let n = n.iter().filter(|x| **x > a).collect::<Vec<&usize>>();
+            for (i, b) in n.iter().enumerate() {
+                for c in n[i + 1..].iter() {
+                    if adj[**b].binary_search(c).is_ok() {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    /// Returns `true` if two distinct objects are linked in either direction.
+    fn adjacent(&self, a: &T, b: &T) -> bool {
+        a != b && (self.has(&(a.clone(), b.clone())) || self.has(&(b.clone(), a.clone())))
+    }
+
+    /// Creates a sorted `Vec` of the objects other than the given object to
+    /// which it is linked in either direction.
+    fn adjacents(&self, v: &T) -> Vec<T> {
+        let mut a = self.neighbours(v).items;
+        a.retain(|x| x != v);
+        a
+    }
+
+    /// Returns the local clustering coefficient of the given object.
+    ///
+    /// This is the fraction of pairs of neighbours of the object that are
+    /// themselves linked, ignoring the direction of the links. An object with
+    /// fewer than two neighbours has a coefficient of `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 0), (2, 3)]);
+    /// assert_eq!(r.local_clustering(&0), 1.0);
+    /// assert_eq!(r.local_clustering(&2), 1.0 / 3.0);
+    /// assert_eq!(r.local_clustering(&3), 0.0);
+    /// ```
+    pub fn local_clustering(&self, v: &T) -> f64 {
+        let n = self.adjacents(v);
+        if n.len() < 2 {
+            return 0.0;
+        }
+        let mut linked = 0;
+        for (i, b) in n.iter().enumerate() {
+            for c in n[i + 1..].iter() {
+                if self.adjacent(b, c) {
+                    linked += 1;
+                }
+            }
+        }
+        linked as f64 / (n.len() * (n.len() - 1) / 2) as f64
+    }
+
+    /// Returns the global clustering coefficient of the `Relation<T>`.
+    ///
+    /// This is the average local clustering coefficient over all objects
+    /// with at least two neighbours, or `0.0` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)]);
+    /// assert_eq!(r.local_clustering(&0), 1.0);
+    /// assert_eq!(r.local_clustering(&1), 1.0);
+    /// assert_eq!(r.local_clustering(&2), 1.0);
+    /// assert_eq!(r.global_clustering(), 1.0);
+    /// ```
+    pub fn global_clustering(&self) -> f64 {
+        let mut total = 0.0;
+        let mut count = 0;
+        for item in self.set.items.iter() {
+            if self.adjacents(item).len() >= 2 {
+                total += self.local_clustering(item);
+                count += 1;
+            }
+        }
+        if count == 0 {
+            0.0
+        } else {
+            total / count as f64
+        }
+    }
+
+    /// Creates the k-core of the `Relation<T>`.
+    ///
+    /// The k-core is the largest sub-relation in which every object is linked
+    /// to at least k other objects, ignoring the direction of the links. It is
+    /// found by repeatedly removing the objects with fewer neighbours.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 0), (2, 3)]);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)]);
+    /// assert_eq!(r.k_core(2), q);
+    /// assert!(r.k_core(2).is_weakly_connected());
+    /// ```
+    pub fn k_core(&self, k: usize) -> Relation<T> {
+        let mut r = self.restrict_to(&self.set);
+        loop {
+            let keep = r.set.items
+                .iter()
+                .filter(|x| r.adjacents(x).len() >= k)
+                .cloned()
+                .collect::<Vec<T>>();
+            if keep.len() == r.set.len() {
+                return r;
+            }
+            r = r.restrict_to(&Set::new(&keep));
+        }
+    }
+
+    /// Creates the `Relation<T>` induced on the given `Set<T>`, keeping only
+    /// the links between its objects.
+    fn restrict_to(&self, set: &Set<T>) -> Relation<T> {
+        Relation::new(set,
+                      &self.links
+                      .iter()
+                      .filter(|x| set.has(&x.0) && set.has(&x.1))
+                      .cloned()
+                      .collect::<Vec<(T, T)>>())
+    }
+
+    /// Returns `true` if the `Relation<T>` is a lattice.
+    ///
+    /// The `Relation<T>` is expected to be a partial order, where the link
+    /// (x, y) means x ≤ y. It is a lattice if every pair of objects has a
+    /// unique least upper bound (join) and a unique greatest lower bound
+    /// (meet).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let diamond = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (1, 1), (2, 2), (3, 3), (0, 1), (0, 2), (1, 3), (2, 3), (0, 3)]);
+    /// assert!(diamond.is_lattice());
+    /// let n = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (1, 1), (2, 2), (3, 3), (0, 2), (1, 2), (1, 3)]);
+    /// assert!(!n.is_lattice());
+    /// ```
+    pub fn is_lattice(&self) -> bool {
+        let items = &self.set.items;
+        let le = |x: &T, y: &T| self.has(&(x.clone(), y.clone()));
+        for a in items.iter() {
+            for b in items.iter() {
+                let upper = items.iter().filter(|u| le(a, u) && le(b, u)).collect::<Vec<&T>>();
+                let lower = items.iter().filter(|l| le(l, a) && le(l, b)).collect::<Vec<&T>>();
+                if upper.iter().filter(|u| upper.iter().all(|w| le(u, w))).count() != 1 ||
+                    lower.iter().filter(|l| lower.iter().all(|w| le(w, l))).count() != 1 {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns the Jaccard similarity of the neighbours of two objects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 2), (0, 3), (1, 2), (1, 3)]);
+    /// assert_eq!(r.neighbour_jaccard(&0, &1), 1.0);
+    /// assert_eq!(r.neighbour_jaccard(&0, &2), 0.0);
+    /// ```
+    pub fn neighbour_jaccard(&self, a: &T, b: &T) -> f64 {
+        self.neighbours(a).jaccard(&self.neighbours(b))
+    }
+
+    /// Creates the two colour classes of the `Relation<T>` if it is
+    /// bipartite.
+    ///
+    /// Ignoring the direction of the links, the objects are coloured by a
+    /// breadth-first search so that no link joins two objects of the same
+    /// class. Returns `None` if this is impossible, i.e. if there is a cycle
+    /// of odd length (including a self-loop).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+    /// assert_eq!(r.bipartition(), Some((Set::new(&vec![0, 2]), Set::new(&vec![1, 3]))));
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)]);
+    /// assert_eq!(q.bipartition(), None);
+    /// ```
+    pub fn bipartition(&self) -> Option<(Set<T>, Set<T>)> {
+        let mut colour: Vec<(T, bool)> = Vec::new();
+        for start in self.set.items.iter() {
+            if colour.iter().any(|x| x.0 == *start) {
+                continue;
+            }
+            let mut i = colour.len();
+            colour.push((start.clone(), false));
+            while i < colour.len() {
+                let (v, c) = colour[i].clone();
+                for n in self.neighbours(&v).items {
+                    match colour.iter().find(|x| x.0 == n).map(|x| x.1) {
+                        Some(d) if d == c => return None,
+                        Some(_) => {}
+                        None => colour.push((n, !c)),
+                    }
+                }
+                i += 1;
+            }
+        }
+        let a = colour.iter().filter(|x| !x.1).map(|x| x.0.clone()).collect::<Vec<T>>();
+        let b = colour.iter().filter(|x| x.1).map(|x| x.0.clone()).collect::<Vec<T>>();
+        Some((Set::new(&a), Set::new(&b)))
+    }
+
+    /// Creates a `Relation<T>` linking each source to each sink it reaches.
+    ///
+    /// The result contains the link (s, t) for every s in `sources` and t in
+    /// `sinks` such that the transitive closure contains (s, t).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]), &vec![(0, 2), (2, 3), (1, 4)]);
+    /// let q = r.reachability_between(&Set::new(&vec![0, 1]), &Set::new(&vec![3, 4]));
+    /// assert_eq!(q, Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]), &vec![(0, 3), (1, 4)]));
+    /// ```
+    pub fn reachability_between(&self, sources: &Set<T>, sinks: &Set<T>) -> Relation<T> {
+        let mut v = Vec::new();
+        for s in sources.items.iter() {
+            for t in self.reachable(s) {
+                if sinks.has(&t) {
+                    v.push((s.clone(), t));
+                }
+            }
+        }
+        v.sort();
+        Relation::new(&self.set, &v)
+    }
+
+    /// Creates a `Vec` of all objects reachable from the given object by
+    /// following at least one link.
+    fn reachable(&self, v: &T) -> Vec<T> {
+        let mut seen = self.links_to(v).items;
+        let mut i = 0;
+        while i < seen.len() {
+            for item in self.links_to(&seen[i]).items {
+                if !seen.contains(&item) {
+                    seen.push(item);
+                }
+            }
+            i += 1;
+        }
+        seen
+    }
+
+    /// Returns the eccentricity of the given object.
+    ///
+    /// The eccentricity is the greatest distance, in number of links, from the
+    /// object to any other object it reaches. Returns `None` if the object
+    /// reaches no other object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]);
+    /// assert_eq!(r.eccentricity(&0), Some(3));
+    /// assert_eq!(r.eccentricity(&2), Some(1));
+    /// assert_eq!(r.eccentricity(&3), None);
+    /// ```
+    pub fn eccentricity(&self, v: &T) -> Option<usize> {
+        self.distances(v).into_iter().filter(|x| x.0 != *v).map(|x| x.1).max()
+    }
+
+    /// Returns the radius of the `Relation<T>`.
+    ///
+    /// The radius is the smallest eccentricity of its objects. Returns `None`
+    /// if no object reaches another.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]);
+    /// assert_eq!(r.sym_closure().radius(), Some(2));
+    /// ```
+    pub fn radius(&self) -> Option<usize> {
+        self.set.items.iter().filter_map(|x| self.eccentricity(x)).min()
+    }
+
+    /// Creates a `Vec` pairing each object reachable from the given object
+    /// with its distance, in breadth-first order starting with the object
+    /// itself at distance 0.
+    fn distances(&self, v: &T) -> Vec<(T, usize)> {
+        let mut seen = vec![(v.clone(), 0)];
+        let mut i = 0;
+        while i < seen.len() {
+            let (u, d) = seen[i].clone();
+            for item in self.links_to(&u).items {
+                if !seen.iter().any(|x| x.0 == item) {
+                    seen.push((item, d + 1));
+                }
+            }
+            i += 1;
+        }
+        seen
+    }
+
+    /// Creates a `Set<T>` containing the objects whose eccentricity equals the
+    /// radius of the `Relation<T>`.
+    ///
+    /// Eccentricities only measure the objects that are actually reached, so
+    /// for a `Relation<T>` that is not strongly connected the center is taken
+    /// over these partial distances. Objects that reach nothing are never part
+    /// of the center.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]),
+    /// &vec![(0, 1), (1, 2), (2, 3), (3, 4)]).sym_closure();
+    /// assert_eq!(r.center(), Set::new(&vec![2]));
+    /// ```
+    pub fn center(&self) -> Set<T> {
+        let radius = self.radius();
+        Set::new(&self.set.items
+                 .iter()
+                 .filter(|x| radius.is_some() && self.eccentricity(x) == radius)
+                 .cloned()
+                 .collect::<Vec<T>>())
+    }
+
+    /// Returns `true` if the `Relation<T>` is a forest.
+    ///
+    /// A `Relation<T>` is said to be a forest if, ignoring the direction of
+    /// its links, it contains no cycle. Unlike a tree, a forest may consist of
+    /// several disconnected parts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]), &vec![(0, 1), (0, 2), (3, 4)])
+    ///         .is_forest());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]), &vec![(0, 1), (1, 2), (2, 0), (3, 4)])
+    ///         .is_forest());
+    /// ```
+    pub fn is_forest(&self) -> bool {
+        if self.links.iter().any(|x| x.0 == x.1) {
+            return false;
+        }
+        let spanning = self.components().iter().map(|x| x.len() - 1).sum::<usize>();
+        let edges = self.set.items
+            .iter()
+            .map(|a| self.adjacents(a).iter().filter(|b| *b > a).count())
+            .sum::<usize>();
+        edges == spanning
+    }
+
+    /// Creates a `Vec` of the connected parts of the `Relation<T>`, ignoring
+    /// the direction of its links.
+    fn components(&self) -> Vec<Vec<T>> {
+        let mut v: Vec<Vec<T>> = Vec::new();
+        for item in self.set.items.iter() {
+            if !v.iter().any(|x| x.contains(item)) {
+                v.push(self.component(item));
+            }
+        }
+        v
+    }
+
+    /// Creates a `BTreeMap` mapping each object to the root of the link
+    /// ending at it.
+    ///
+    /// This describes a tree or a forest by its parent links. Fails if an
+    /// object is the tail of links from more than one object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation, RelationError};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (0, 2), (2, 3)]);
+    /// let m = r.parent_map().unwrap();
+    /// assert_eq!(m.get(&3), Some(&2));
+    /// assert_eq!(m.get(&1), Some(&0));
+    /// assert_eq!(m.get(&0), None);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 2), (1, 2)]);
+    /// assert_eq!(q.parent_map(), Err(RelationError::MultipleParents(2)));
+    /// ```
+    pub fn parent_map(&self) -> Result<BTreeMap<T, T>, RelationError<T>> {
+        let mut m = BTreeMap::new();
+        for item in self.links.iter() {
+            if let Some(p) = m.insert(item.1.clone(), item.0.clone()) {
+                if p != item.0 {
+                    return Err(RelationError::MultipleParents(item.1.clone()));
+                }
+            }
+        }
+        Ok(m)
+    }
+
+    /// Returns the depth of the given object in a tree.
+    ///
+    /// The depth is the number of links followed upwards from the object to
+    /// the root of its tree. Returns `None` if the object is not in the set,
+    /// if some object has more than one parent, or if the walk upwards runs
+    /// into a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]), &vec![(0, 1), (0, 2), (2, 3)]);
+    /// assert_eq!(r.depth(&0), Some(0));
+    /// assert_eq!(r.depth(&2), Some(1));
+    /// assert_eq!(r.depth(&3), Some(2));
+    /// assert_eq!(r.depth(&5), None);
+    /// ```
+    pub fn depth(&self, v: &T) -> Option<usize> {
+        if !self.set.has(v) {
+            return None;
+        }
+        let parents = match self.parent_map() {
+            Ok(m) => m,
+            Err(_) => return None,
+        };
+        let mut d = 0;
+        let mut current = v;
+        while let Some(p) = parents.get(current) {
+            d += 1;
+            if d > parents.len() {
+                return None;
+            }
+            current = p;
+        }
+        Some(d)
+    }
+
+    /// Creates the `Relation<T>` induced on the objects satisfying a
+    /// predicate.
+    ///
+    /// The set is restricted to the objects for which `keep` returns `true`,
+    /// and the links touching any other object are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]), &vec![(0, 1), (0, 2), (2, 4), (3, 4)]);
+    /// let q = Relation::new(&Set::new(&vec![0, 2, 4]), &vec![(0, 2), (2, 4)]);
+    /// assert_eq!(r.induced(|x| x % 2 == 0), q);
+    /// ```
+    pub fn induced<F: Fn(&T) -> bool>(&self, keep: F) -> Relation<T> {
+        self.restrict_to(&Set::new(&self.set.items
+                                   .iter()
+                                   .filter(|x| keep(x))
+                                   .cloned()
+                                   .collect::<Vec<T>>()))
+    }
+
+    /// Creates a bit-packed adjacency matrix of the `Relation<T>`.
+    ///
+    /// Returns the objects of the set in sorted order and the matrix in
+    /// row-major order, one bit per pair: the bit for the link from the i-th
+    /// to the j-th object is bit `(i * n + j) % 64` of word `(i * n + j) / 64`.
+    /// Use `bitmatrix_get` to read it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation, bitmatrix_get};
+    /// let r = Relation::new(&Set::new(&vec![2, 0, 1]), &vec![(0, 1), (1, 2), (2, 2)]);
+    /// let (order, bits) = r.to_bitmatrix();
+    /// assert_eq!(order, vec![0, 1, 2]);
+    /// for (i, x) in order.iter().enumerate() {
+    ///     for (j, y) in order.iter().enumerate() {
+    ///         assert_eq!(bitmatrix_get(&bits, order.len(), i, j), r.has(&(*x, *y)));
+    ///     }
+    /// }
+    /// ```
+    pub fn to_bitmatrix(&self) -> (Vec<T>, Vec<u64>) {
+        let order = self.set.items.clone();
+        let n = order.len();
+        let mut bits = vec![0u64; (n * n).div_ceil(64)];
+        for item in self.links.iter() {
+            if let (Ok(i), Ok(j)) = (order.binary_search(&item.0), order.binary_search(&item.1)) {
+                let k = i * n + j;
+                bits[k / 64] |= 1 << (k % 64);
+            }
+        }
+        (order, bits)
+    }
+
+    /// Creates the objects of the set in sorted order, and the links as pairs
+    /// of indices into them.
+    ///
+    /// The pairs are sorted and without repeats. Links to objects outside of
+    /// the set are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec!['c', 'a', 'b']), &vec![('c', 'a'), ('a', 'b')]);
+    /// let (elements, pairs) = r.to_indexed();
+    /// assert_eq!(elements, vec!['a', 'b', 'c']);
+    /// assert_eq!(pairs, vec![(0, 1), (2, 0)]);
+    /// assert_eq!(Relation::from_indexed(&elements, &pairs), r);
+    /// ```
+    pub fn to_indexed(&self) -> (Vec<T>, Vec<(usize, usize)>) {
+        let elements = self.set.items.clone();
+        let mut pairs = Vec::new();
+        for item in self.links.iter() {
+            if let (Ok(i), Ok(j)) = (elements.binary_search(&item.0), elements.binary_search(&item.1)) {
+                pairs.push((i, j));
+            }
+        }
+        pairs.sort();
+        pairs.dedup();
+        (elements, pairs)
+    }
+
+    /// Creates a new `Relation<T>` on the given objects, with a link for each
+    /// pair of indices into them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::from_indexed(&[10, 20, 30], &[(0, 2), (2, 1)]);
+    /// assert_eq!(r, Relation::new(&Set::new(&vec![10, 20, 30]), &vec![(10, 30), (30, 20)]));
+    /// ```
+    pub fn from_indexed(elements: &[T], pairs: &[(usize, usize)]) -> Relation<T> {
+        Relation::new(&Set::new(&elements.to_vec()),
+                      &pairs.iter()
+                      .map(|x| (elements[x.0].clone(), elements[x.1].clone()))
+                      .collect::<Vec<(T, T)>>())
+    }
+
+    /// Creates a sorted `Vec` of the links of the `Relation<T>` with their
+    /// direction ignored.
+    ///
+    /// Each pair of linked objects appears once as `(min, max)`, and each
+    /// self-loop as `(x, x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]),
+    /// &vec![(0, 1), (1, 0), (2, 1), (1, 2), (2, 2)]);
+    /// assert_eq!(r.undirected_edges(), vec![(0, 1), (1, 2), (2, 2)]);
+    /// ```
+    pub fn undirected_edges(&self) -> Vec<(T, T)> {
+        let mut v = self.links
+            .iter()
+            .map(|x| if x.0 <= x.1 { x.clone() } else { (x.1.clone(), x.0.clone()) })
+            .collect::<Vec<(T, T)>>();
+        v.sort();
+        v.dedup();
+        v
+    }
+
+    /// Returns the number of distinct paths from one object to another.
+    ///
+    /// Paths are only counted in an acyclic `Relation<T>`; returns `None` if
+    /// the `Relation<T>` contains a cycle, or if the count does not fit in a
+    /// `u64`. The objects are put in topological order once and each link is
+    /// followed once, for a cost of O(V log V + E log V). An object has a
+    /// single, empty path to itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (0, 2), (1, 3), (2, 3)]);
+    /// assert_eq!(r.path_count(&0, &3), Some(2));
+    /// assert_eq!(r.path_count(&3, &0), Some(0));
+    /// let q = Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1), (1, 0)]);
+    /// assert_eq!(q.path_count(&0, &1), None);
+    /// let diamonds = (0..70).flat_map(|i| vec![(3 * i, 3 * i + 1), (3 * i, 3 * i + 2),
+    ///                                           (3 * i + 1, 3 * i + 3), (3 * i + 2, 3 * i + 3)])
+    ///                       .collect::<Vec<(u32, u32)>>();
+    /// let d = Relation::new(&Set::new(&(0..211).collect()), &diamonds);
+    /// assert_eq!(d.path_count(&0, &189), Some(1 << 63));
+    /// assert_eq!(d.path_count(&0, &210), None);
+    /// assert_eq!(d.path_count(&150, &210), Some(1 << 20));
+    /// ```
+    pub fn path_count(&self, from: &T, to: &T) -> Option<u64> {
+        let nodes = union(&self.set, &union(&self.domain(), &self.codomain())).items;
+        let mut adj = vec![Vec::new(); nodes.len()];
+        for item in self.links.iter() {
+            if let (Ok(i), Ok(j)) = (nodes.binary_search(&item.0), nodes.binary_search(&item.1)) {
+                adj[i].push(j);
+            }
+        }
+        for x in adj.iter_mut() {
+            x.sort();
+            x.dedup();
+        }
+        let order = topological_order(&adj)?;
+        if from == to {
+            return Some(1);
+        }
+        let (from, to) = match (nodes.binary_search(from), nodes.binary_search(to)) {
+            (Ok(i), Ok(j)) => (i, j),
+            _ => return Some(0),
+        };
+        let mut count: Vec<Option<u64>> = vec![Some(0); nodes.len()];
+        count[to] = Some(1);
+        for v in order.into_iter().rev().filter(|x| *x != to) {
+            count[v] = adj[v].iter().try_fold(0u64, |c, w| c.checked_add(count[*w]?));
+        }
+        count[from]
+    }
+
+    /// Returns the number of squaring steps needed to reach the transitive
+    /// closure.
+    ///
+    /// Each step replaces the links R with R ∪ (R ∘ R), doubling the length
+    /// of the paths covered, so at most ⌈log2(n)⌉ steps add links. Returns the
+    /// number of steps that added at least one link.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]),
+    /// &vec![(0, 1), (1, 2), (2, 3), (3, 4)]);
+    /// assert_eq!(r.closure_iterations(), 2);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (0, 2)]);
+    /// assert_eq!(q.closure_iterations(), 0);
+    /// ```
+    pub fn closure_iterations(&self) -> usize {
+        let mut v = self.links.clone();
+        v.sort();
+        v.dedup();
+        let mut count = 0;
+        loop {
+            let mut w = v.clone();
+            for item in v.iter() {
+                for item2 in v.iter().filter(|x| x.0 == item.1) {
+                    w.push((item.0.clone(), item2.1.clone()));
+                }
+            }
+            w.sort();
+            w.dedup();
+            if w.len() == v.len() {
+                return count;
+            }
+            v = w;
+            count += 1;
+        }
+    }
+
+    /// Returns `true` if the `Relation<T>` is a quasi-order.
+    ///
+    /// A `Relation<T>` is said to be a quasi-order, or preorder, if it is
+    /// reflexive and transitive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0), (1, 1)])
+    ///         .is_quasi_order());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1)])
+    ///         .is_quasi_order());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1, 2]),
+    ///                        &vec![(0, 0), (1, 1), (2, 2), (0, 1), (1, 2)])
+    ///         .is_quasi_order());
+    /// ```
+    pub fn is_quasi_order(&self) -> bool {
+        self.is_reflexive() && self.is_transitive()
+    }
+
+    /// Returns `true` if the `Relation<T>` is connex.
+    ///
+    /// A `Relation<T>` is said to be connex on a set if it contains (x, y) or
+    /// (y, x) for all x and y in the set, so that any two objects are
+    /// comparable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 1)])
+    ///         .is_connex());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1)])
+    ///         .is_connex());
+    /// ```
+    pub fn is_connex(&self) -> bool {
+        self.set.items.iter().all(|x| {
+            self.set.items.iter().all(|y| {
+                self.has(&(x.clone(), y.clone())) || self.has(&(y.clone(), x.clone()))
+            })
+        })
+    }
+
+    /// Returns `true` if the `Relation<T>` is a partial order.
+    ///
+    /// A `Relation<T>` is said to be a partial order if it is reflexive,
+    /// antisymmetric and transitive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let divides = Relation::new(&Set::new(&vec![1, 2, 3]),
+    ///                             &vec![(1, 1), (2, 2), (3, 3), (1, 2), (1, 3)]);
+    /// assert!(divides.is_partial_order());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (1, 1), (0, 1), (1, 0)])
+    ///         .is_partial_order());
+    /// ```
+    pub fn is_partial_order(&self) -> bool {
+        self.is_reflexive() && self.is_antisymmetric() && self.is_transitive()
+    }
+
+    /// Returns `true` if the `Relation<T>` is a total order.
+    ///
+    /// A `Relation<T>` is said to be a total order if it is a connex partial
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let le = Relation::new(&Set::new(&vec![0, 1, 2]),
+    ///                        &vec![(0, 0), (1, 1), (2, 2), (0, 1), (0, 2), (1, 2)]);
+    /// assert!(le.is_total_order());
+    /// let divides = Relation::new(&Set::new(&vec![1, 2, 3]),
+    ///                             &vec![(1, 1), (2, 2), (3, 3), (1, 2), (1, 3)]);
+    /// assert!(!divides.is_total_order());
+    /// ```
+    pub fn is_total_order(&self) -> bool {
+        self.is_partial_order() && self.is_connex()
+    }
+
+    /// Returns `true` if the `Relation<T>` is an equivalence relation.
+    ///
+    /// A `Relation<T>` is said to be an equivalence relation if it is
+    /// reflexive, symmetric and transitive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1, 2]),
+    ///                       &vec![(0, 0), (1, 1), (2, 2), (0, 1), (1, 0)])
+    ///         .is_equivalence());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1, 2]),
+    ///                        &vec![(0, 0), (1, 1), (2, 2), (0, 1), (1, 0), (1, 2), (2, 1)])
+    ///         .is_equivalence());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0)])
+    ///         .is_equivalence());
+    /// ```
+    pub fn is_equivalence(&self) -> bool {
+        self.is_reflexive() && self.is_symmetric() && self.is_transitive()
+    }
+
+    /// Creates the partition of the set into the equivalence classes of the
+    /// `Relation<T>`.
+    ///
+    /// If the `Relation<T>` is not an equivalence relation, the classes are
+    /// those of its reflexive, symmetric and transitive closure, i.e. the
+    /// weakly connected components.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]),
+    ///                       &vec![(0, 0), (1, 1), (2, 2), (0, 1), (1, 0)]);
+    /// assert_eq!(r.equivalence_classes(),
+    ///            Set::new(&vec![Set::new(&vec![0, 1]), Set::new(&vec![2])]));
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (2, 1)]);
+    /// assert_eq!(q.equivalence_classes(),
+    ///            Set::new(&vec![Set::new(&vec![0, 1, 2]), Set::new(&vec![3])]));
+    /// ```
+    pub fn equivalence_classes(&self) -> Set<Set<T>> {
+        Set::new(&self.components().iter().map(|x| Set::new(x)).collect::<Vec<Set<T>>>())
+    }
+
+    /// Creates the strict part of the `Relation<T>`.
+    ///
+    /// The strict part contains the link (x, y) whenever the `Relation<T>`
+    /// contains (x, y) but not (y, x). For a weak preference this is the
+    /// strict preference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let weak = Relation::new(&Set::new(&vec![0, 1, 2]),
+    /// &vec![(0, 0), (1, 1), (2, 2), (0, 1), (1, 0), (0, 2), (1, 2)]);
+    /// assert!(weak.is_quasi_order());
+    /// let strict = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 2), (1, 2)]);
+    /// assert_eq!(weak.strict_companion(), strict);
+    /// ```
+    pub fn strict_companion(&self) -> Relation<T> {
+        Relation::new(&self.set,
+                      &self.links
+                      .iter()
+                      .filter(|x| !self.has(&(x.1.clone(), x.0.clone())))
+                      .cloned()
+                      .collect::<Vec<(T, T)>>())
+    }
+
+    /// Creates the `Relation<T>` with the given object removed from the set,
+    /// along with every link touching it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3, 4]), &vec![(0, 2), (1, 2), (2, 3), (2, 4)]);
+    /// assert!(r.is_weakly_connected());
+    /// let q = r.without_node(&2);
+    /// assert!(!q.is_weakly_connected());
+    /// assert_eq!(q, Relation::new(&Set::new(&vec![0, 1, 3, 4]), &vec![]));
+    /// ```
+    pub fn without_node(&self, v: &T) -> Relation<T> {
+        self.induced(|x| x != v)
+    }
+
+    /// Creates the `Relation<T>` in which the link between two objects is
+    /// contracted into a single new object.
+    ///
+    /// Both objects are replaced by `new` in the set and in every link, and the
+    /// self-loops created by the contraction are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if neither (a, b) nor (b, a) is a link of the `Relation<T>`, or
+    /// if `new` is an object of the set other than `a` and `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]);
+    /// let q = Relation::new(&Set::new(&vec![0, 3, 5]), &vec![(0, 5), (5, 3)]);
+    /// assert_eq!(r.contract_edge(&1, &2, 5), q);
+    /// let p = Relation::new(&Set::new(&vec![0, 1, 3]), &vec![(0, 1), (1, 3)]);
+    /// assert_eq!(r.contract_edge(&1, &2, 1), p);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// r.contract_edge(&0, &1, 2);
+    /// ```
+    pub fn contract_edge(&self, a: &T, b: &T, new: T) -> Relation<T> {
+        if !self.has(&(a.clone(), b.clone())) && !self.has(&(b.clone(), a.clone())) {
+            panic!("cannot contract a missing link");
+        }
+        if new != *a && new != *b && self.set.has(&new) {
+            panic!("the new object is already in the set");
+        }
+        let merged = |x: &T| x == a || x == b;
+        let rename = |x: &T| if merged(x) { new.clone() } else { x.clone() };
+        let mut set = self.set.items.iter().map(&rename).collect::<Vec<T>>();
+        set.sort();
+        set.dedup();
+        let mut v = Vec::new();
+        for item in self.links.iter() {
+            if item.0 == item.1 || !merged(&item.0) || !merged(&item.1) {
+                v.push((rename(&item.0), rename(&item.1)));
+            }
+        }
+        v.sort();
+        v.dedup();
+        Relation::new(&Set::new(&set), &v)
+    }
+
+    /// Creates a text rendering of the adjacency matrix of the `Relation<T>`.
+    ///
+    /// The objects label the rows and columns in sorted order, and each cell
+    /// shows `1` if the row object is linked to the column object and `.`
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 2)]);
+    /// assert_eq!(r.to_pretty_matrix(), "  0 1 2\n0 . 1 .\n1 . . 1\n2 . . 1\n");
+    /// ```
+    pub fn to_pretty_matrix(&self) -> String
+        where T: Display
+    {
+        let labels = self.set.items.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        let w = labels.iter().map(|x| x.chars().count()).max().unwrap_or(0);
+        let mut s = format!("{:w$}", "", w = w);
+        for l in labels.iter() {
+            s.push_str(&format!(" {:>w$}", l, w = w));
+        }
+        s.push('\n');
+        for (x, l) in self.set.items.iter().zip(labels.iter()) {
+            s.push_str(&format!("{:>w$}", l, w = w));
+            for y in self.set.items.iter() {
+                let c = if self.has(&(x.clone(), y.clone())) { "1" } else { "." };
+                s.push_str(&format!(" {:>w$}", c, w = w));
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    /// Returns `true` if the `Relation<T>` is a permutation of its set.
+    ///
+    /// A `Relation<T>` is said to be a permutation if every object of the set
+    /// is the root of exactly one link and the tail of exactly one link, and
+    /// no link leaves the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)])
+    ///         .is_permutation());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 1)])
+    ///         .is_permutation());
+    /// ```
+    pub fn is_permutation(&self) -> bool {
+        self.links.iter().all(|x| self.set.has(&x.0) && self.set.has(&x.1)) &&
+            self.set.items.iter().all(|x| {
+                self.links_to(x).len() == 1 && self.links_from(x).len() == 1
+            })
+    }
+
+    /// Creates a `Vec` of the cycles of a permutation.
+    ///
+    /// Each cycle starts at its smallest object, and the cycles are ordered by
+    /// their first object. Fixed points are cycles of length one. Returns an
+    /// empty `Vec` if the `Relation<T>` is not a permutation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 2), (2, 1), (1, 0), (3, 3)]);
+    /// assert_eq!(r.cycle_decomposition(), vec![vec![0, 2, 1], vec![3]]);
+    /// ```
+    pub fn cycle_decomposition(&self) -> Vec<Vec<T>> {
+        let mut v: Vec<Vec<T>> = Vec::new();
+        if !self.is_permutation() {
+            return v;
+        }
+        for start in self.set.items.iter() {
+            if v.iter().any(|c| c.contains(start)) {
+                continue;
+            }
+            let mut cycle = vec![start.clone()];
+            let mut next = self.links_to(start).items[0].clone();
+            while next != *start {
+                cycle.push(next.clone());
+                next = self.links_to(&next).items[0].clone();
+            }
+            v.push(cycle);
+        }
+        v
+    }
+
+    /// Returns the betweenness of the given object.
+    ///
+    /// For every pair of other objects (s, t) such that s reaches t, this adds
+    /// the fraction of the shortest paths from s to t that pass through the
+    /// object. The shortest paths are counted with one breadth-first search
+    /// per object over an adjacency list built once, for a cost of
+    /// O(V * (V + E)). The numbers of shortest paths are kept as `f64`, so
+    /// they do not overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]).sym_closure();
+    /// assert_eq!(r.betweenness(&1), 2.0);
+    /// assert_eq!(r.betweenness(&0), 0.0);
+    /// assert_eq!(r.betweenness(&2), 0.0);
+    /// let diamonds = (0..70).flat_map(|i| vec![(3 * i, 3 * i + 1), (3 * i, 3 * i + 2),
+    ///                                           (3 * i + 1, 3 * i + 3), (3 * i + 2, 3 * i + 3)])
+    ///                       .collect::<Vec<(u32, u32)>>();
+    /// let d = Relation::new(&Set::new(&(0..211).collect()), &diamonds);
+    /// assert_eq!(d.betweenness(&3), 3.0 * 207.0);
+    /// ```
+    pub fn betweenness(&self, v: &T) -> f64 {
+        let n = match self.set.items.binary_search(v) {
+            Ok(n) => n,
+            Err(_) => return 0.0,
+        };
+        let adj = self.adjacency();
+        let through = path_counts(&adj, n);
+        let mut total = 0.0;
+        for s in (0..adj.len()).filter(|x| *x != n) {
+            let from = path_counts(&adj, s);
+            let sv = match from[n] {
+                Some(x) => x,
+                None => continue,
+            };
+            for t in (0..adj.len()).filter(|x| *x != s && *x != n) {
+                if let (Some(st), Some(vt)) = (from[t], through[t]) {
+                    if sv.0 + vt.0 == st.0 {
+                        total += sv.1 * vt.1 / st.1;
+                    }
+                }
+            }
+        }
+        total
+    }
+
+    /// Creates, for each object of the set by index, the sorted indices of
+    /// the objects it links to.
+    fn adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adj = vec![Vec::new(); self.set.len()];
+        for item in self.links.iter() {
+            if let (Ok(i), Ok(j)) = (self.set.items.binary_search(&item.0),
+                                     self.set.items.binary_search(&item.1)) {
+                adj[i].push(j);
+            }
+        }
+        for x in adj.iter_mut() {
+            x.sort();
+            x.dedup();
+        }
+        adj
+    }
+
+    /// Creates the complement of the `Relation<T>` as an undirected graph.
+    ///
+    /// The result links, in both directions, every pair of distinct objects
+    /// that are not linked in either direction in the `Relation<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 2), (2, 0)]);
+    /// assert_eq!(r.complement_undirected(), q);
+    /// ```
+    pub fn complement_undirected(&self) -> Relation<T> {
+        let mut v = Vec::new();
+        for a in self.set.items.iter() {
+            for b in self.set.items.iter() {
+                if a != b && !self.adjacent(a, b) {
+                    v.push((a.clone(), b.clone()));
+                }
+            }
+        }
+        v.sort();
+        Relation::new(&self.set, &v)
+    }
+
+    /// Creates the least `Relation<T>` containing this one that is closed
+    /// under a generator of links.
+    ///
+    /// The links returned by `gen` are added with `add_link` until it returns
+    /// no new link. Links leaving the set are ignored, so this always ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1)]);
+    /// let q = r.close_under(|x| {
+    ///     x.undirected_edges().into_iter().flat_map(|l| vec![(l.0, l.1), (l.1, l.0)]).collect()
+    /// });
+    /// assert_eq!(q, r.sym_closure());
+    /// ```
+    pub fn close_under<F: Fn(&Relation<T>) -> Vec<(T, T)>>(&self, gen: F) -> Relation<T> {
+        let mut r = Relation::new(&self.set, &self.links);
+        loop {
+            let len = r.links.len();
+            r.add_links(gen(&r));
+            if r.links.len() == len {
+                return r;
+            }
+        }
+    }
+
+    /// Returns the sum of the degrees of the neighbours of the given object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let star = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (0, 2), (0, 3)]);
+    /// assert_eq!(star.neighbour_degree_sum(&0), 3);
+    /// assert_eq!(star.neighbour_degree_sum(&1), 3);
+    /// ```
+    pub fn neighbour_degree_sum(&self, v: &T) -> usize {
+        self.neighbours(v).items.iter().map(|x| self.degree(x)).sum()
+    }
+
+    /// Creates a `BTreeMap` mapping each pair (x, y) such that x reaches y to
+    /// a shortest path from x to y.
+    ///
+    /// Each path lists the objects visited, starting with x and ending with y.
+    /// An object reaches itself only through a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]);
+    /// let m = r.reachability_with_paths();
+    /// assert_eq!(m.get(&(0, 3)), Some(&vec![0, 1, 2, 3]));
+    /// assert_eq!(m.get(&(1, 2)), Some(&vec![1, 2]));
+    /// assert_eq!(m.get(&(3, 0)), None);
+    /// assert_eq!(m.len(), 6);
+    /// ```
+    pub fn reachability_with_paths(&self) -> BTreeMap<(T, T), Vec<T>> {
+        let mut m = BTreeMap::new();
+        for x in self.set.items.iter() {
+            let tree = self.bfs_tree(x);
+            for (i, item) in tree.iter().enumerate().skip(1) {
+                m.insert((x.clone(), item.0.clone()), path_in(&tree, i));
+            }
+            if let Some(i) = tree.iter().position(|u| self.has(&(u.0.clone(), x.clone()))) {
+                let mut p = path_in(&tree, i);
+                p.push(x.clone());
+                m.insert((x.clone(), x.clone()), p);
+            }
+        }
+        m
+    }
+
+    /// Creates a `Vec` of each object reachable from the given object with
+    /// the index of the object it was first reached from, in breadth-first
+    /// order starting with the object itself.
+    fn bfs_tree(&self, v: &T) -> Vec<(T, usize)> {
+        let mut seen = vec![(v.clone(), 0)];
+        let mut i = 0;
+        while i < seen.len() {
+            for item in self.links_to(&seen[i].0).items {
+                if !seen.iter().any(|x| x.0 == item) {
+                    seen.push((item, i));
+                }
+            }
+            i += 1;
+        }
+        seen
+    }
+
+    /// Creates the coarsest refinement of a partition that is stable with
+    /// respect to the `Relation<T>`.
+    ///
+    /// A partition is stable if any two objects of the same block are linked
+    /// to the same blocks. Blocks are split by the blocks their objects are
+    /// linked to until no block splits any more. Links to objects outside of
+    /// the partition are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 2), (1, 2), (2, 3)]);
+    /// let p = r.coarsest_stable_partition(&[Set::new(&vec![0, 1, 2, 3])]);
+    /// assert_eq!(p, vec![Set::new(&vec![3]), Set::new(&vec![2]), Set::new(&vec![0, 1])]);
+    /// ```
+    pub fn coarsest_stable_partition(&self, initial: &[Set<T>]) -> Vec<Set<T>> {
+        let mut blocks = initial.iter()
+            .filter(|x| !x.is_empty())
+            .map(|x| x.items.clone())
+            .collect::<Vec<Vec<T>>>();
+        loop {
+            let mut refined = Vec::new();
+            for block in blocks.iter() {
+                let mut groups: BTreeMap<Vec<usize>, Vec<T>> = BTreeMap::new();
+                for x in block.iter() {
+                    let mut key = self.links_to(x)
+                        .items
+                        .iter()
+                        .filter_map(|y| blocks.iter().position(|b| b.contains(y)))
+                        .collect::<Vec<usize>>();
+                    key.sort();
+                    key.dedup();
+                    groups.entry(key).or_default().push(x.clone());
+                }
+                refined.extend(groups.into_iter().map(|x| x.1));
+            }
+            if refined.len() == blocks.len() {
+                return blocks.iter().map(|x| Set::new(x)).collect();
+            }
+            blocks = refined;
+        }
+    }
+
+    /// Creates a maximum matching between two `Set<T>`.
+    ///
+    /// The links from `left` to `right` are the possible pairs. A matching is
+    /// a `Vec` of such links, sorted, in which no object appears twice. It is
+    /// grown one augmenting path at a time over adjacency lists built once,
+    /// for a cost of O(V * E * log V).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 10, 11, 12]),
+    /// &vec![(0, 10), (0, 11), (1, 10), (2, 10), (2, 12)]);
+    /// let m = r.max_bipartite_matching(&Set::new(&vec![0, 1, 2]), &Set::new(&vec![10, 11, 12]));
+    /// assert_eq!(m, vec![(0, 11), (1, 10), (2, 12)]);
+    /// ```
+    pub fn max_bipartite_matching(&self, left: &Set<T>, right: &Set<T>) -> Vec<(T, T)> {
+        let mut adj: BTreeMap<T, Vec<T>> = BTreeMap::new();
+        for item in self.links.iter() {
+            if left.has(&item.0) && right.has(&item.1) {
+                adj.entry(item.0.clone()).or_default().push(item.1.clone());
+            }
+        }
+        for x in adj.values_mut() {
+            x.sort();
+            x.dedup();
+        }
+        let mut matched: BTreeMap<T, T> = BTreeMap::new();
+        for l in left.items.iter() {
+            let mut visited = BTreeSet::new();
+            augment(l, &adj, &mut matched, &mut visited);
+        }
+        let mut v = matched.into_iter().map(|x| (x.1, x.0)).collect::<Vec<(T, T)>>();
+        v.sort();
+        v
+    }
+
+    /// Returns `true` if the `Relation<T>` has an Eulerian path.
+    ///
+    /// Ignoring the direction of the links and the self-loops, an Eulerian
+    /// path uses every link exactly once. It exists if the linked objects are
+    /// all connected and either none or two of them have an odd degree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// assert!(Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)])
+    ///         .has_eulerian_path());
+    /// assert!(Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)])
+    ///         .has_eulerian_path());
+    /// assert!(!Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (0, 2), (0, 3)])
+    ///         .has_eulerian_path());
+    /// ```
+    pub fn has_eulerian_path(&self) -> bool {
+        let linked = self.components().into_iter().filter(|x| x.len() > 1).count();
+        let odd = self.set.items.iter().filter(|x| self.adjacents(x).len() % 2 == 1).count();
+        linked <= 1 && (odd == 0 || odd == 2)
+    }
+
+    /// Creates a `Set<T>` containing the articulation points of the
+    /// `Relation<T>`.
+    ///
+    /// Ignoring the direction of the links, an articulation point is an
+    /// object whose removal splits its connected part in two or more. They are
+    /// found with a single depth-first search comparing discovery times with
+    /// the lowest time reachable through a back link.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// assert_eq!(r.articulation_points(), Set::new(&vec![1]));
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)]);
+    /// assert_eq!(q.articulation_points().len(), 0);
+    /// ```
+    pub fn articulation_points(&self) -> Set<T> {
+        let (nodes, adj) = self.undirected_adjacency();
+        let mut search = Lowlink::new(nodes.len());
+        for u in 0..nodes.len() {
+            if search.disc[u] == 0 {
+                search.visit(&adj, u);
             }
         }
+        Set::new(&nodes
+                 .iter()
+                 .zip(search.cut.iter())
+                 .filter(|x| *x.1)
+                 .map(|x| x.0.clone())
+                 .collect::<Vec<T>>())
     }
 
-    /// Returns `true` if this `Relation<T>` contains the given link.
-    pub fn has(&self, l: &(T, T)) -> bool {
-        self.links.iter().any(|x| x == l)
+    /// Creates a sorted `Vec` of the bridges of the `Relation<T>`.
+    ///
+    /// Ignoring the direction of the links, a bridge is a link whose removal
+    /// splits its connected part in two. Each bridge is given as `(min, max)`.
+    /// They are found with the same depth-first search as the articulation
+    /// points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (2, 1), (2, 3)]);
+    /// assert_eq!(r.bridges(), vec![(0, 1), (1, 2), (2, 3)]);
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 0), (2, 3)]);
+    /// assert_eq!(q.bridges(), vec![(2, 3)]);
+    /// ```
+    pub fn bridges(&self) -> Vec<(T, T)> {
+        let (nodes, adj) = self.undirected_adjacency();
+        let mut search = Lowlink::new(nodes.len());
+        for u in 0..nodes.len() {
+            if search.disc[u] == 0 {
+                search.visit(&adj, u);
+            }
+        }
+        let mut v = search.bridges
+            .iter()
+            .map(|&(a, b)| (nodes[a.min(b)].clone(), nodes[a.max(b)].clone()))
+            .collect::<Vec<(T, T)>>();
+        v.sort();
+        v
     }
 
-    /// Creates a `Set<T>` containing all objects to which the given object
-    /// is linked.
-    pub fn neighbours(&self, v: &T) -> Set<T> {
-        union(&self.links_to(&v), &self.links_from(&v))
+    /// Creates the sorted objects of the set along with, for each of them,
+    /// the indices of the other objects it is linked to in either direction.
+    fn undirected_adjacency(&self) -> (Vec<T>, Vec<Vec<usize>>) {
+        let mut adj = vec![Vec::new(); self.set.len()];
+        for (i, x) in self.adjacency().iter().enumerate() {
+            for j in x.iter().filter(|j| **j != i) {
+                adj[i].push(*j);
+                adj[*j].push(i);
+            }
+        }
+        for x in adj.iter_mut() {
+            x.sort();
+            x.dedup();
+        }
+        (self.set.items.clone(), adj)
     }
 
-    /// Creates a `Set<T>` containing all objects to which the given object reaches.
-    pub fn links_to(&self, v: &T) -> Set<T> {
-        let mut a = Vec::new();
-        for item in self.links.iter() {
-            if item.0 == *v {
-                a.push(item.1.clone());
-            }
+    /// Creates a `BTreeMap` assigning a colour to each object so that no two
+    /// linked objects share a colour.
+    ///
+    /// Ignoring the direction of the links and the self-loops, the objects
+    /// are coloured in sorted order, each taking the smallest colour not yet
+    /// used by its neighbours. This is not always the smallest colouring.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (2, 0)]);
+    /// let c = r.greedy_color();
+    /// assert_eq!(c.values().max(), Some(&2));
+    /// assert_eq!((c[&0], c[&1], c[&2]), (0, 1, 2));
+    /// ```
+    pub fn greedy_color(&self) -> BTreeMap<T, usize> {
+        let mut colour = BTreeMap::new();
+        for x in self.set.items.iter() {
+            let used = self.adjacents(x)
+                .iter()
+                .filter_map(|y| colour.get(y).cloned())
+                .collect::<Vec<usize>>();
+            let c = (0..).find(|c| !used.contains(c)).unwrap_or(0);
+            colour.insert(x.clone(), c);
         }
-        a.sort();
-        a.dedup();
-        Set::new(&a)
+        colour
     }
 
-    /// Creates a `Set<T>` containing all objects from which the given object is
-    /// reachable.
-    pub fn links_from(&self, v: &T) -> Set<T> {
-        let mut a = Vec::new();
-        for item in self.links.iter() {
-            if item.1 == *v {
-                a.push(item.0.clone());
-            }
+    /// Creates a `BTreeMap` mapping each object the given object reaches to
+    /// the number of times the link between them is stored.
+    ///
+    /// `add_link` never stores a link twice, but `new` keeps every link it is
+    /// given, so a `Relation<T>` may hold repeated links.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (0, 1), (0, 2)]);
+    /// let m = r.successor_multiplicity(&0);
+    /// assert_eq!(m.get(&1), Some(&2));
+    /// assert_eq!(m.get(&2), Some(&1));
+    /// assert_eq!(r.successor_multiplicity(&1).len(), 0);
+    /// ```
+    pub fn successor_multiplicity(&self, v: &T) -> BTreeMap<T, usize> {
+        let mut m = BTreeMap::new();
+        for item in self.links.iter().filter(|x| x.0 == *v) {
+            *m.entry(item.1.clone()).or_insert(0) += 1;
         }
-        a.sort();
-        a.dedup();
-        Set::new(&a)
+        m
     }
 
-    /// Returns the number of objects to which the given object is linked.
-    pub fn degree(&self, v: &T) -> usize {
-        self.neighbours(v).len()
+    /// Creates a certificate string such that isomorphic `Relation<T>` share
+    /// the same certificate.
+    ///
+    /// The objects are coloured by their number of links in and out, then
+    /// repeatedly recoloured by the colours they link to and from until the
+    /// colouring stops changing. The certificate lists the sizes, the colours
+    /// and the coloured links. This is a heuristic: some relations that are not
+    /// isomorphic, such as regular graphs of the same degree, cannot be told
+    /// apart this way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let a = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (1, 3)]);
+    /// let b = Relation::new(&Set::new(&vec![5, 6, 7, 8]), &vec![(8, 5), (5, 6), (5, 7)]);
+    /// let c = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]);
+    /// assert_eq!(a.iso_certificate(), b.iso_certificate());
+    /// assert_ne!(a.iso_certificate(), c.iso_certificate());
+    /// ```
+    pub fn iso_certificate(&self) -> String {
+        let nodes = self.set.items.clone();
+        let mut links = self.links
+            .iter()
+            .filter_map(|x| match (nodes.binary_search(&x.0), nodes.binary_search(&x.1)) {
+                (Ok(i), Ok(j)) => Some((i, j)),
+                _ => None,
+            })
+            .collect::<Vec<(usize, usize)>>();
+        links.sort();
+        links.dedup();
+        let mut colour = vec![0; nodes.len()];
+        let mut count = 0;
+        loop {
+            let sig = (0..nodes.len())
+                .map(|i| {
+                    let mut out = links.iter()
+                        .filter(|l| l.0 == i)
+                        .map(|l| colour[l.1])
+                        .collect::<Vec<usize>>();
+                    let mut inc = links.iter()
+                        .filter(|l| l.1 == i)
+                        .map(|l| colour[l.0])
+                        .collect::<Vec<usize>>();
+                    out.sort();
+                    inc.sort();
+                    (colour[i], out, inc)
+                })
+                .collect::<Vec<(usize, Vec<usize>, Vec<usize>)>>();
+            let mut distinct = sig.clone();
+            distinct.sort();
+            distinct.dedup();
+            colour = sig.iter().map(|x| distinct.binary_search(x).unwrap_or(0)).collect();
+            if distinct.len() == count {
+                break;
+            }
+            count = distinct.len();
+        }
+        let mut colours = colour.clone();
+        colours.sort();
+        let mut coloured = links.iter()
+            .map(|l| (colour[l.0], colour[l.1]))
+            .collect::<Vec<(usize, usize)>>();
+        coloured.sort();
+        format!("{}:{}:{:?}:{:?}", nodes.len(), links.len(), colours, coloured)
     }
 
-    /// Creates a `Set<T>` containing all objects that are the root of at
-    /// least one link.
-    pub fn domain(&self) -> Set<T> {
-        let mut a = self.links.iter().map(|x| x.0.clone()).collect::<Vec<T>>();
-        a.sort();
-        a.dedup();
-        Set::new(&a)
+    /// Sorts the links and removes the repeated ones.
+    ///
+    /// `new` keeps every link it is given, so this turns such a `Relation<T>`
+    /// into one where each link is stored once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let mut r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(1, 2), (0, 1), (1, 2), (0, 1)]);
+    /// assert_eq!(r.link_count(), 4);
+    /// r.dedup_links();
+    /// assert_eq!(r.link_count(), 2);
+    /// assert_eq!(r, Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]));
+    /// ```
+    pub fn dedup_links(&mut self) {
+        self.links.sort();
+        self.links.dedup();
     }
 
-    /// Creates a `Set<T>` containing all objects that are the tail of at
-    /// least one link.
-    pub fn codomain(&self) -> Set<T> {
-        let mut a = self.links.iter().map(|x| x.1.clone()).collect::<Vec<T>>();
-        a.sort();
-        a.dedup();
-        Set::new(&a)
+    /// Creates a `Vec` of the objects along a shortest path from one object to
+    /// another, including both, or returns `None` if there is no such path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (0, 2), (1, 3), (2, 1)]);
+    /// assert_eq!(r.shortest_path(&0, &3), Some(vec![0, 1, 3]));
+    /// assert_eq!(r.shortest_path(&2, &2), Some(vec![2]));
+    /// assert_eq!(r.shortest_path(&3, &0), None);
+    /// ```
+    pub fn shortest_path(&self, from: &T, to: &T) -> Option<Vec<T>> {
+        let tree = self.bfs_tree(from);
+        tree.iter().position(|x| x.0 == *to).map(|i| path_in(&tree, i))
     }
 
-    /// Returns `true` if the `Relation<T>` is reflexive.
+    /// Creates the quotient of the `Relation<T>` by a partition of its set.
     ///
-    /// A `Relation<T>` is said to be reflexive on a set if it contains the link
-    /// (x, x) for all x in the set.
+    /// Each object is replaced by the index of its block in `partition`, and
+    /// each link by the link between the blocks of its objects, stored once.
+    /// Links within a block become self-loops on that block, unless
+    /// `drop_self_loops` is `true`, in which case they are left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an object of the set belongs to none of the blocks, or to
+    /// more than one.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use relations::{Set, Relation};
-    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 1)])
-    ///        .is_reflexive());
-    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0)])
-    ///        .is_reflexive());
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (0, 2), (1, 3), (2, 3)]);
+    /// let p = [Set::new(&vec![0, 1]), Set::new(&vec![2, 3])];
+    /// let q = r.quotient_by(&p, false);
+    /// assert_eq!(q, Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 1)]));
+    /// let q = r.quotient_by(&p, true);
+    /// assert_eq!(q, Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1)]));
     /// ```
-    pub fn is_reflexive(&self) -> bool {
-        self.set.items.iter().all(|x| self.has(&(x.clone(), x.clone())))
+    ///
+    /// ```rust,should_panic
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1)]);
+    /// r.quotient_by(&[Set::new(&vec![0, 1]), Set::new(&vec![1, 2])], false);
+    /// ```
+    pub fn quotient_by(&self, partition: &[Set<T>], drop_self_loops: bool) -> Relation<usize> {
+        for item in self.set.items.iter() {
+            match partition.iter().filter(|b| b.has(item)).count() {
+                0 => panic!("the partition does not cover the set"),
+                1 => {}
+                _ => panic!("the blocks of the partition overlap"),
+            }
+        }
+        let block = |x: &T| partition.iter().position(|b| b.has(x));
+        let mut v = self.links
+            .iter()
+            .filter_map(|x| match (block(&x.0), block(&x.1)) {
+                (Some(a), Some(b)) if !(drop_self_loops && a == b) => Some((a, b)),
+                _ => None,
+            })
+            .collect::<Vec<(usize, usize)>>();
+        v.sort();
+        v.dedup();
+        Relation::new(&Set::from_range(0, partition.len()), &v)
     }
 
-    /// Returns `true` if the `Relation<T>` is symmetric.
-    ///
-    /// A `Relation<T>` is said to be symmetric on a set if it contains the link
-    /// (x, y) for all (y, x).
+    /// Returns `true` if the transitive closure of the `Relation<T>` equals
+    /// the given `Relation<T>`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use relations::{Set, Relation};
-    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0)])
-    ///         .is_symmetric());
-    /// assert!(!Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 1)])
-    ///         .is_symmetric());
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2)]);
+    /// assert!(r.trans_closure_equals(&Relation::new(&Set::new(&vec![0, 1, 2]),
+    /// &vec![(0, 1), (1, 2), (0, 2)])));
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]);
+    /// assert!(q.trans_closure_equals(&Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 1), (1, 2), (2, 3), (0, 2), (1, 3), (0, 3)])));
+    /// assert!(!q.trans_closure_equals(&q));
     /// ```
-    pub fn is_symmetric(&self) -> bool {
-        self.links.iter().all(|x| self.has(&(x.1.clone(), x.0.clone())))
+    pub fn trans_closure_equals(&self, expected: &Relation<T>) -> bool {
+        self.trans_closure() == *expected
     }
 
-    /// Returns `true` if the `Relation<T>` is transitive.
+    /// Creates a `BTreeMap` mapping each object reachable from the given root
+    /// to its immediate dominator.
     ///
-    /// A `Relation<T>` is said to be transitive on a set if for all (x, z),
-    /// there is an object, y, in the set such that the `Relation<T>` contains
-    /// (x, y) and (y, z).
+    /// An object `d` dominates `v` if every path from the root to `v` passes
+    /// through `d`; the immediate dominator of `v` is the dominator of `v`
+    /// closest to it. The root itself is not a key of the map. Uses the
+    /// iterative algorithm of Cooper, Harvey and Kennedy.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use relations::{Set, Relation};
-    /// assert!(Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 0), (0, 1), (1, 0)])
-    ///         .is_transitive());
-    /// assert!(!Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 0), (0, 1), (2, 1)])
-    ///         .is_transitive());
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3, 4, 5]),
+    /// &vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 1)]);
+    /// let d = r.dominators(&0);
+    /// assert_eq!(d.get(&1), Some(&0));
+    /// assert_eq!(d.get(&2), Some(&0));
+    /// assert_eq!(d.get(&3), Some(&0));
+    /// assert_eq!(d.get(&4), Some(&3));
+    /// assert_eq!(d.get(&0), None);
+    /// assert_eq!(d.get(&5), None);
     /// ```
-    pub fn is_transitive(&self) -> bool {
-        let test = self.set.items.iter();
-        for item1 in test.clone() {
-            for item2 in test.clone() {
-                if self.has(&(item1.clone(), item2.clone())) {
-                    if !test.clone().any(|x| {
-                        self.has(&(item1.clone(), x.clone())) &&
-                            self.has(&(x.clone(), item2.clone()))
-                    }) {
-                        return false;
+    pub fn dominators(&self, root: &T) -> BTreeMap<T, T> {
+        let mut m = BTreeMap::new();
+        if !self.set.has(root) {
+            return m;
+        }
+        let mut order = Vec::new();
+        let mut seen = vec![root.clone()];
+        let mut stack = vec![(root.clone(), self.links_to(root).items, 0)];
+        while let Some(top) = stack.last_mut() {
+            if top.2 < top.1.len() {
+                let w = top.1[top.2].clone();
+                top.2 += 1;
+                if !seen.contains(&w) {
+                    seen.push(w.clone());
+                    let next = self.links_to(&w).items;
+                    stack.push((w, next, 0));
+                }
+            } else {
+                order.push(top.0.clone());
+                stack.pop();
+            }
+        }
+        order.reverse();
+        let preds: Vec<Vec<usize>> = order.iter()
+            .map(|x| self.links.iter()
+                 .filter(|l| l.1 == *x)
+                 .filter_map(|l| order.iter().position(|y| *y == l.0))
+                 .collect())
+            .collect();
+        let mut idom: Vec<Option<usize>> = vec![None; order.len()];
+        idom[0] = Some(0);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for b in 1..order.len() {
+                let mut new = None;
+                for p in preds[b].iter() {
+                    if idom[*p].is_none() {
+                        continue;
                     }
+                    new = match new {
+                        None => Some(*p),
+                        Some(q) => {
+                            let (mut x, mut y) = (*p, q);
+                            while x != y {
+                                while x > y {
+                                    x = idom[x].unwrap();
+                                }
+                                while y > x {
+                                    y = idom[y].unwrap();
+                                }
+                            }
+                            Some(x)
+                        }
+                    };
+                }
+                if new != idom[b] {
+                    idom[b] = new;
+                    changed = true;
                 }
             }
         }
-        true
+        for (b, d) in idom.iter().enumerate().skip(1) {
+            m.insert(order[b].clone(), order[d.unwrap()].clone());
+        }
+        m
     }
 
-    /// Creates the `Relation<T>` transitive closure.
+    /// Returns `true` if no two distinct objects of the given `Set<T>` are
+    /// linked.
+    ///
+    /// The `Relation<T>` is expected to be a partial order, where the link
+    /// (x, y) means x ≤ y. An antichain is a set of objects no two of which
+    /// are comparable. Self-loops are ignored.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use relations::{Set, Relation};
-    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
-    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1)]);
-    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
-    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1), (1, 1), (2, 2), (3, 3)]);
-    /// assert_eq!(r.refl_closure(), q);
+    /// let diamond = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (1, 1), (2, 2), (3, 3), (0, 1), (0, 2), (1, 3), (2, 3), (0, 3)]);
+    /// assert!(diamond.is_antichain(&Set::new(&vec![1, 2])));
+    /// assert!(diamond.is_antichain(&Set::new(&vec![3])));
+    /// assert!(!diamond.is_antichain(&Set::new(&vec![0, 3])));
+    /// assert!(!diamond.is_antichain(&Set::new(&vec![1, 2, 3])));
     /// ```
-    pub fn refl_closure(&self) -> Relation<T> {
-        let mut v = Vec::new();
-        for item in self.set.items.iter() {
-            v.push((item.clone(), item.clone()));
-        }
-        rel_union(&self, &Relation::new(&self.set, &v))
+    pub fn is_antichain(&self, subset: &Set<T>) -> bool {
+        !self.links.iter().any(|x| x.0 != x.1 && subset.has(&x.0) && subset.has(&x.1))
     }
 
-    /// Creates the `Relation<T>` transitive closure.
+    /// Returns the width of the `Relation<T>`.
+    ///
+    /// The `Relation<T>` is expected to be a partial order, where the link
+    /// (x, y) means x ≤ y. Its width is the size of its largest antichain. By
+    /// Dilworth's theorem this is also the least number of chains covering
+    /// the set, which is the size of the set less a maximum matching of the
+    /// strict order.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use relations::{Set, Relation};
-    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
-    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1)]);
-    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
-    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1), (0, 3), (2, 3)]);
-    /// assert_eq!(r.trans_closure(), q);
+    /// let chain = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (1, 2), (0, 2)]);
+    /// assert_eq!(chain.width(), 1);
+    /// let antichain = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 0), (1, 1), (2, 2)]);
+    /// assert_eq!(antichain.width(), 3);
+    /// let diamond = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (1, 1), (2, 2), (3, 3), (0, 1), (0, 2), (1, 3), (2, 3), (0, 3)]);
+    /// assert_eq!(diamond.width(), 2);
     /// ```
-    pub fn trans_closure(&self) -> Relation<T> {
+    pub fn width(&self) -> usize {
+        self.set.len() - self.chain_matching().len()
+    }
+
+    /// Creates a maximum matching of the strict part of the transitive
+    /// closure, pairing each object with the next one in its chain.
+    fn chain_matching(&self) -> Vec<(T, T)> {
+        let closure = self.trans_closure();
+        let strict = Relation::new(&self.set,
+                                   &closure.links
+                                   .iter()
+                                   .filter(|x| x.0 != x.1)
+                                   .cloned()
+                                   .collect::<Vec<(T, T)>>());
+        strict.max_bipartite_matching(&self.set, &self.set)
+    }
+
+    /// Creates a least number of chains covering the `Relation<T>`.
+    ///
+    /// The `Relation<T>` is expected to be a partial order, where the link
+    /// (x, y) means x ≤ y. Each object belongs to exactly one chain, and each
+    /// chain is listed from its least object upwards. There are as many
+    /// chains as the width of the `Relation<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let diamond = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
+    /// &vec![(0, 0), (1, 1), (2, 2), (3, 3), (0, 1), (0, 2), (1, 3), (2, 3), (0, 3)]);
+    /// let c = diamond.chain_cover();
+    /// assert_eq!(c.len(), diamond.width());
+    /// assert_eq!(c, vec![vec![0, 1, 3], vec![2]]);
+    /// let antichain = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![]);
+    /// assert_eq!(antichain.chain_cover(), vec![vec![0], vec![1], vec![2]]);
+    /// ```
+    pub fn chain_cover(&self) -> Vec<Vec<T>> {
+        let next = self.chain_matching().into_iter().collect::<BTreeMap<T, T>>();
         let mut v = Vec::new();
-        v.extend_from_slice(self.links.as_slice());
-        for k in self.set.items.iter() {
-            for i in self.set.items.iter() {
-                for j in self.set.items.iter() {
-                    if !self.has(&(i.clone(), j.clone())) && self.has(&(i.clone(), k.clone())) &&
-                        self.has(&(k.clone(), j.clone())) {
-                            v.push((i.clone(), j.clone()));
-                        }
-                }
+        for item in self.set.items.iter() {
+            if next.values().any(|x| x == item) {
+                continue;
             }
+            let mut chain = vec![item.clone()];
+            while let Some(x) = next.get(&chain[chain.len() - 1]) {
+                chain.push(x.clone());
+            }
+            v.push(chain);
         }
-        Relation::new(&self.set, &v)
+        v
     }
 
-    /// Creates the `Relation<T>` symmetric closure.
+    /// Creates a `Vec` of the objects in order if the `Relation<T>` is a
+    /// single chain.
     ///
-    /// The symmetric closure is generated by adding the link (y, x) for all
-    /// (x, y) already in the `Relation<T>`.
+    /// The `Relation<T>` is a single chain if its links form one path, without
+    /// repeats, through every object of the set. Returns `None` otherwise,
+    /// including when the set is empty.
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # use relations::{Set, Relation};
-    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
-    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1)]);
-    /// let q = Relation::new(&Set::new(&vec![0, 1, 2, 3]),
-    /// &vec![(0, 0), (0, 1), (1, 3), (2, 1), (1, 0), (3, 1), (1, 2)]);
-    /// assert_eq!(r.sym_closure(), q);
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(2, 0), (0, 3), (3, 1)]);
+    /// assert_eq!(r.as_chain(), Some(vec![2, 0, 3, 1]));
+    /// let q = Relation::new(&Set::new(&vec![0, 1, 2]), &vec![(0, 1), (0, 2)]);
+    /// assert_eq!(q.as_chain(), None);
+    /// let c = Relation::new(&Set::new(&vec![0, 1]), &vec![(0, 1), (1, 0)]);
+    /// assert_eq!(c.as_chain(), None);
     /// ```
-    pub fn sym_closure(&self) -> Relation<T> {
-        let mut v = Vec::new();
-        v.extend_from_slice(self.links.as_slice());
-        let mut v = Relation::new(&self.set, &v);
-        for i in self.links.iter() {
-            v.add_link((i.1.clone(), i.0.clone()));
+    pub fn as_chain(&self) -> Option<Vec<T>> {
+        let mut sources = self.set.items.iter().filter(|x| self.links_from(x).is_empty());
+        let mut v = vec![sources.next()?.clone()];
+        while v.len() <= self.set.len() {
+            let next = self.links_to(&v[v.len() - 1]);
+            match next.as_singleton() {
+                Some(x) if self.links_from(x).is_singleton() => v.push(x.clone()),
+                Some(_) => return None,
+                None if next.is_empty() => break,
+                None => return None,
+            }
+        }
+        if v.len() == self.set.len() {
+            Some(v)
+        } else {
+            None
         }
-        v
+    }
+
+    /// Creates a `Set<(T, T)>` of the links that the given closure adds to the
+    /// `Relation<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation, ClosureKind};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2), (2, 3)]);
+    /// assert_eq!(r.closure_added_links(ClosureKind::Transitive),
+    ///            Set::new(&vec![(0, 2), (0, 3), (1, 3)]));
+    /// assert_eq!(r.closure_added_links(ClosureKind::Symmetric),
+    ///            Set::new(&vec![(1, 0), (2, 1), (3, 2)]));
+    /// assert_eq!(r.closure_added_links(ClosureKind::Reflexive).len(), 4);
+    /// ```
+    pub fn closure_added_links(&self, kind: ClosureKind) -> Set<(T, T)> {
+        let closure = match kind {
+            ClosureKind::Reflexive => self.refl_closure(),
+            ClosureKind::Symmetric => self.sym_closure(),
+            ClosureKind::Transitive => self.trans_closure(),
+        };
+        Set::new(&closure.links
+                 .into_iter()
+                 .filter(|x| !self.has(x))
+                 .collect::<Vec<(T, T)>>())
     }
 }
 
@@ -395,8 +3751,8 @@ pub fn rel_inter<T: Clone + Ord>(this: &Relation<T>, that: &Relation<T>) -> Rela
     Relation::new(&inter(&this.set, &that.set),
     &this.links
     .iter()
-    .filter(|x| that.has(&x))
-    .map(|x| x.clone())
+    .filter(|x| that.has(x))
+    .cloned()
     .collect())
 }
 
@@ -416,8 +3772,8 @@ pub fn rel_compl<T: Clone + Ord>(this: &Relation<T>, that: &Relation<T>) -> Rela
     Relation::new(&compl(&this.set, &that.set),
     &this.links
     .iter()
-    .filter(|x| !that.has(&x))
-    .map(|x| x.clone())
+    .filter(|x| !that.has(x))
+    .cloned()
     .collect())
 }
 
@@ -442,3 +3798,243 @@ pub fn rel_compo<T: Clone + Ord>(this: &Relation<T>, that: &Relation<T>) -> Rela
     }
     Relation::new(&union(&this.domain(), &that.codomain()), &v)
 }
+
+/// Creates the diagonal `Relation<T>` of a `Set<T>`.
+///
+/// The diagonal, or identity relation, on a set links every object to
+/// itself and to nothing else.
+///
+/// # Examples
+///
+/// ```rust
+/// # use relations::{Set, Relation, diagonal};
+/// let a = Set::new(&vec![0, 1, 2]);
+/// let c = Relation::new(&a, &vec![(0, 0), (1, 1), (2, 2)]);
+/// assert_eq!(diagonal(&a), c);
+/// assert!(diagonal(&a).is_reflexive());
+/// ```
+pub fn diagonal<T: Clone + Ord>(set: &Set<T>) -> Relation<T> {
+    Relation::new(set,
+                  &set.items
+                  .iter()
+                  .map(|x| (x.clone(), x.clone()))
+                  .collect::<Vec<(T, T)>>())
+}
+
+/// Returns `true` if the bit for the pair (i, j) is set in a bit-packed
+/// matrix of size n, as created by `Relation::to_bitmatrix`.
+pub fn bitmatrix_get(bits: &[u64], n: usize, i: usize, j: usize) -> bool {
+    let k = i * n + j;
+    bits[k / 64] & (1 << (k % 64)) != 0
+}
+
+/// Looks for an augmenting path from the given left object, updating the map
+/// from right objects to their matched left objects if one is found.
+fn augment<T: Ord + Clone>(l: &T, adj: &BTreeMap<T, Vec<T>>, matched: &mut BTreeMap<T, T>,
+                           visited: &mut BTreeSet<T>) -> bool {
+    for r in adj.get(l).into_iter().flatten() {
+        if !visited.insert(r.clone()) {
+            continue;
+        }
+        let free = match matched.get(r).cloned() {
+            Some(other) => augment(&other, adj, matched, visited),
+            None => true,
+        };
+        if free {
+            matched.insert(r.clone(), l.clone());
+            return true;
+        }
+    }
+    false
+}
+
+/// Creates the indices in topological order, removing one with no remaining
+/// incoming links at a time, or returns `None` if there is a cycle.
+fn topological_order(adj: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let mut incoming = vec![0; adj.len()];
+    for w in adj.iter().flatten() {
+        incoming[*w] += 1;
+    }
+    let mut order = (0..adj.len()).filter(|x| incoming[*x] == 0).collect::<Vec<usize>>();
+    let mut i = 0;
+    while i < order.len() {
+        for w in adj[order[i]].iter() {
+            incoming[*w] -= 1;
+            if incoming[*w] == 0 {
+                order.push(*w);
+            }
+        }
+        i += 1;
+    }
+    if order.len() == adj.len() {
+        Some(order)
+    } else {
+        None
+    }
+}
+
+/// Creates, for each index, the distance from the given index and the number
+/// of shortest paths reaching it, or `None` if it is not reached.
+///
+/// The number of paths is kept as an `f64`, since it can grow exponentially
+/// with the distance.
+fn path_counts(adj: &[Vec<usize>], v: usize) -> Vec<Option<(usize, f64)>> {
+    let mut seen = vec![None; adj.len()];
+    seen[v] = Some((0, 1.0));
+    let mut queue = vec![v];
+    let mut i = 0;
+    while i < queue.len() {
+        let u = queue[i];
+        let (d, c) = seen[u].unwrap();
+        for w in adj[u].iter() {
+            match seen[*w] {
+                Some((e, k)) if e == d + 1 => seen[*w] = Some((e, k + c)),
+                Some(_) => {}
+                None => {
+                    seen[*w] = Some((d + 1, c));
+                    queue.push(*w);
+                }
+            }
+        }
+        i += 1;
+    }
+    seen
+}
+
+/// Creates the path from the root of a breadth-first tree, as created by
+/// `Relation::bfs_tree`, to the object at the given index.
+fn path_in<T: Clone>(tree: &[(T, usize)], mut i: usize) -> Vec<T> {
+    let mut p = vec![tree[i].0.clone()];
+    while i != 0 {
+        i = tree[i].1;
+        p.push(tree[i].0.clone());
+    }
+    p.reverse();
+    p
+}
+
+/// Holds the state of a depth-first search computing discovery times and
+/// low links over an undirected adjacency list.
+struct Lowlink {
+    /// The discovery time of each node, starting at 1, or 0 if unvisited.
+    disc: Vec<usize>,
+    /// The lowest discovery time reachable from the subtree of each node.
+    low: Vec<usize>,
+    /// Whether each node is an articulation point.
+    cut: Vec<bool>,
+    /// The links whose removal disconnects their endpoints.
+    bridges: Vec<(usize, usize)>,
+    time: usize,
+}
+
+impl Lowlink {
+    fn new(n: usize) -> Lowlink {
+        Lowlink {
+            disc: vec![0; n],
+            low: vec![0; n],
+            cut: vec![false; n],
+            bridges: Vec::new(),
+            time: 0,
+        }
+    }
+
+    /// Gives the next discovery time to a node.
+    fn discover(&mut self, u: usize) {
+        self.time += 1;
+        self.disc[u] = self.time;
+        self.low[u] = self.time;
+    }
+
+    /// Searches the nodes reachable from the given root, keeping the nodes
+    /// being visited on an explicit stack along with their parent and the
+    /// index of their next neighbour.
+    fn visit(&mut self, adj: &[Vec<usize>], root: usize) {
+        self.discover(root);
+        let mut children = 0;
+        let mut stack = vec![(root, None, 0)];
+        while let Some(top) = stack.last_mut() {
+            let (u, parent) = (top.0, top.1);
+            if top.2 < adj[u].len() {
+                let w = adj[u][top.2];
+                top.2 += 1;
+                if Some(w) == parent {
+                    continue;
+                }
+                if self.disc[w] != 0 {
+                    self.low[u] = self.low[u].min(self.disc[w]);
+                    continue;
+                }
+                if u == root {
+                    children += 1;
+                }
+                self.discover(w);
+                stack.push((w, Some(u), 0));
+            } else {
+                stack.pop();
+                if let Some(p) = parent {
+                    self.low[p] = self.low[p].min(self.low[u]);
+                    if p != root && self.low[u] >= self.disc[p] {
+                        self.cut[p] = true;
+                    }
+                    if self.low[u] > self.disc[p] {
+                        self.bridges.push((p, u));
+                    }
+                }
+            }
+        }
+        if children > 1 {
+            self.cut[root] = true;
+        }
+    }
+}
+
+#[cfg(feature = "graphml")]
+impl<T: Ord + Clone + Display> Relation<T> {
+    /// Creates a GraphML document describing the `Relation<T>`.
+    ///
+    /// Every object of the set becomes a node, including the objects without
+    /// links, and every link becomes a directed edge.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use relations::{Set, Relation};
+    /// let r = Relation::new(&Set::new(&vec![0, 1, 2, 3]), &vec![(0, 1), (1, 2)]);
+    /// let xml = r.to_graphml();
+    /// assert!(xml.starts_with("<?xml"));
+    /// assert_eq!(xml.matches("<node ").count(), 4);
+    /// assert_eq!(xml.matches("<edge ").count(), 2);
+    /// ```
+    pub fn to_graphml(&self) -> String {
+        let nodes = union(&self.set, &union(&self.domain(), &self.codomain())).items;
+        let id = |x: &T| nodes.iter().position(|y| y == x).unwrap_or(0);
+        let mut links = self.links.clone();
+        links.sort();
+        links.dedup();
+        let mut s = String::new();
+        s.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        s.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        s.push_str("  <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n");
+        s.push_str("  <graph id=\"G\" edgedefault=\"directed\">\n");
+        for (i, item) in nodes.iter().enumerate() {
+            s.push_str(&format!("    <node id=\"n{}\"><data key=\"label\">{}</data></node>\n",
+                                i, xml_escape(&item.to_string())));
+        }
+        for (i, link) in links.iter().enumerate() {
+            s.push_str(&format!("    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\"/>\n",
+                                i, id(&link.0), id(&link.1)));
+        }
+        s.push_str("  </graph>\n");
+        s.push_str("</graphml>\n");
+        s
+    }
+}
+
+/// Escapes the characters that are not allowed in XML character data.
+#[cfg(feature = "graphml")]
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}